@@ -19,6 +19,9 @@ pub enum ContractError {
     #[error("At least one active order")]
     ActiveOrder {},
 
+    #[error("Banned, address: {address}")]
+    Banned { address: String },
+
     #[error("{0}")]
     PaymentError(#[from] PaymentError),
 
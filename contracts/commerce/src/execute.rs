@@ -3,6 +3,7 @@ use cosmwasm_std::{
 };
 use cw20::{Balance, Cw20Coin};
 use trust::msg::ExecuteMsg as TrustExecuteMsg;
+use trust::state::TradeRole;
 
 use crate::query::query_withdrawable_balance;
 use crate::state::admins::ADMIN_LIST;
@@ -11,7 +12,7 @@ use crate::state::listing::{listings, next_listing_id, Attributes, Listing, List
 use crate::state::order::{next_order_id, orders, Order, OrderItem, OrderStatus, TrackingInfo};
 use crate::util::{
     can_execute, eval_cost, map_validate, must_pay, send_cw20_tokens, validate_empty_orders,
-    validate_listing,
+    validate_listing, validate_not_banned,
 };
 use crate::ContractError;
 
@@ -275,6 +276,13 @@ pub fn execute_complete_order(
                 return Err(ContractError::Unauthorized {});
             }
 
+            // Banned buyers and sellers cannot be reviewed, their orders can only be refunded
+            let traders = [vec![order.buyer.clone()], admin_list.admins.clone()].concat();
+            validate_not_banned(deps.as_ref(), &config.trust_contract, &traders)?;
+
+            // The order's value is used by the trust contract to weight reviews
+            let order_value = eval_cost(deps.as_ref(), order.items.clone())?;
+
             orders().remove(deps.storage, order.id)?;
 
             // Buyer leaves review to every seller
@@ -286,6 +294,8 @@ pub fn execute_complete_order(
                     peer: admin.to_string(),
                     reviewer: order.buyer.to_string(),
                     order_id: order.id,
+                    order_value: Some(order_value),
+                    peer_role: Some(TradeRole::Seller),
                 })
                 .map(|msg| {
                     let msg = to_binary(&msg).unwrap();
@@ -301,6 +311,8 @@ pub fn execute_complete_order(
                 peer: order.buyer.to_string(),
                 reviewer: admin_list.admins.first().unwrap().to_string(),
                 order_id: order.id,
+                order_value: Some(order_value),
+                peer_role: Some(TradeRole::Buyer),
             };
 
             let seller_message = WasmMsg::Execute {
@@ -386,6 +398,10 @@ pub fn execute_create_order(
         ));
     };
 
+    // Banned buyers and sellers cannot trade
+    let traders = [vec![sender.clone()], ADMIN_LIST.load(deps.storage)?.admins].concat();
+    validate_not_banned(deps.as_ref(), &config.trust_contract, &traders)?;
+
     let order = Order {
         id: next_order_id(deps.storage)?,
         buyer: sender,
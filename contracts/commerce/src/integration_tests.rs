@@ -1,9 +1,22 @@
 #![cfg(test)]
 
-use cosmwasm_std::{coins, to_binary, Addr, Empty, Uint128};
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::{
+    coin, coins, to_binary, Addr, Binary, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env,
+    MessageInfo, Response, StakingMsg, StdResult, Uint128, Validator, WasmMsg,
+};
 use cw20::{Cw20Coin, Cw20Contract, Cw20ExecuteMsg};
-use cw_multi_test::{App, Contract, ContractWrapper, Executor};
-use trust::state::{ReviewResult, TrustScoreParams};
+use cw_multi_test::{App, Contract, ContractWrapper, Executor, StakingInfo};
+use trust::helpers::review_commitment;
+use trust::msg::{MigrateMsg, StakeSourceMsg, StakingSourceMsg, VouchingParamsMsg};
+use trust::state::{
+    legacy_pending_reviews, AppealDecision, AppealStatus, BayesianRatingModelParams,
+    IneligibleReviewPolicy, LegacyConfig, LegacyPendingReview, LegacyTrustData, LegacyTrustInfo,
+    LegacyTrustScoreParams, LinearModelParams, LogStakeModelParams, PairReviewLimit, ReviewComment,
+    ReviewResult, ReviewWeightParams, ReviewerEligibility, ScoreModel, SourceStake, StakeSource,
+    Tier, TradeRole, TrustData, VoucherEligibility, VouchingParams, LEGACY_CONFIG,
+    LEGACY_TRUST_INFO,
+};
 
 use crate::{
     msg::{ExecuteMsg, InstantiateMsg, ReceiveMsg},
@@ -45,10 +58,47 @@ pub fn contract_trust() -> Box<dyn Contract<Empty>> {
         trust::contract::execute,
         trust::contract::instantiate,
         trust::contract::query,
-    );
+    )
+    .with_migrate(trust::contract::migrate);
     Box::new(contract)
 }
 
+// A trust contract storing its config and trust accounts as the first release did
+pub fn contract_legacy_trust() -> Box<dyn Contract<Empty>> {
+    fn instantiate(
+        deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        msg: (
+            LegacyConfig,
+            Vec<(Addr, LegacyTrustInfo)>,
+            Vec<LegacyPendingReview>,
+        ),
+    ) -> StdResult<Response> {
+        cw2::set_contract_version(deps.storage, "crates.io:trust", "0.1.0")?;
+        LEGACY_CONFIG.save(deps.storage, &msg.0)?;
+        for (address, trust_info) in msg.1 {
+            LEGACY_TRUST_INFO.save(deps.storage, &address, &trust_info)?;
+        }
+        for pending_review in msg.2 {
+            legacy_pending_reviews().save(
+                deps.storage,
+                pending_review.peer.clone(),
+                &pending_review,
+            )?;
+        }
+        Ok(Response::new())
+    }
+    fn execute(_: DepsMut, _: Env, _: MessageInfo, _: Empty) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+    fn query(_: Deps, _: Env, _: Empty) -> StdResult<Binary> {
+        to_binary(&Empty {})
+    }
+
+    Box::new(ContractWrapper::new(execute, instantiate, query))
+}
+
 const COMMERCE: &str = "contract3";
 const CW20: &str = "contract0";
 const CW20_STAKE: &str = "contract1";
@@ -56,6 +106,7 @@ const TRUST: &str = "contract2";
 
 const ADMIN: &str = "admin";
 const BUYER: &str = "buyer";
+const VALIDATOR: &str = "validator";
 
 // Initial contract setup
 fn setup_contract() -> App {
@@ -64,11 +115,38 @@ fn setup_contract() -> App {
 
     let init_funds = coins(2000, "ujuno");
 
-    let mut router = App::new(|router, _, storage| {
+    let mut router = App::new(|router, api, storage| {
         router
             .bank
             .init_balance(storage, &admin, init_funds)
             .unwrap();
+
+        // set up a validator for native staking
+        router
+            .staking
+            .setup(
+                storage,
+                StakingInfo {
+                    bonded_denom: String::from("ujuno"),
+                    unbonding_time: 60,
+                    apr: Decimal::percent(10),
+                },
+            )
+            .unwrap();
+        router
+            .staking
+            .add_validator(
+                api,
+                storage,
+                &mock_env().block,
+                Validator {
+                    address: String::from(VALIDATOR),
+                    commission: Decimal::percent(10),
+                    max_commission: Decimal::percent(20),
+                    max_change_rate: Decimal::percent(1),
+                },
+            )
+            .unwrap();
     });
 
     // set up cw20 contract with some tokens
@@ -98,7 +176,7 @@ fn setup_contract() -> App {
     let cw20_stake_id = router.store_code(contract_cw20_stake());
     let msg = cw20_stake::msg::InstantiateMsg {
         token_address: cw20_addr.to_string(),
-        owner: None,
+        owner: Some(admin.to_string()),
         manager: None,
         unstaking_duration: None,
     };
@@ -112,21 +190,79 @@ fn setup_contract() -> App {
     // set up trust contract
     let trust_id = router.store_code(contract_trust());
     let msg = trust::msg::InstantiateMsg {
-        maintainer: admin.to_string(),
-        staking_contract: cw20_stake_addr.to_string(),
-        commerce_code_id: commerce_id,
+        maintainer: Some(admin.to_string()),
+        staking_sources: vec![StakingSourceMsg {
+            source: StakeSourceMsg::Cw20Stake {
+                contract: cw20_stake_addr.to_string(),
+            },
+            weight: Decimal::one(),
+            denom_multiplier: 1,
+            max_staked_tokens: Uint128::new(5000),
+        }],
+        commerce_code_ids: vec![commerce_id],
         review_interval: 86400u64,
         max_staked_days: 240,
-        max_staked_tokens: Uint128::new(5000),
+        min_sync_interval: 86400,
         max_rating: 50,
-        trust_score_params: TrustScoreParams {
+        rating_half_life: 2_592_000,
+        score_model: ScoreModel::Linear(LinearModelParams {
             base_score: 500,
-            denom_multiplier: 1,
             rating_multiplier: 25,
             stake_amount_denominator: 10,
             min_stake_days: 1,
             rating_floor_denominator: 10,
+            max_score: 1500,
+        }),
+        review_weight: ReviewWeightParams {
+            reference_score: 500,
+            reference_order_value: Uint128::new(2000),
+            min_weight: Decimal::percent(10),
+            max_weight: Decimal::percent(200),
+        },
+        reviewer_eligibility: ReviewerEligibility {
+            min_score: 0,
+            min_stake: Decimal::zero(),
+            min_account_age: 0,
+            min_order_value: Uint128::zero(),
+            ineligible_reviews: IneligibleReviewPolicy::Reject,
+        },
+        pair_review_limit: PairReviewLimit {
+            window: 0,
+            max_weight: Decimal::zero(),
+        },
+        appeal_window: 86400,
+        vouching: VouchingParamsMsg {
+            token: cw20_addr.to_string(),
+            denom_multiplier: 1,
+            score_per_token: Decimal::percent(10),
+            reference_score: 500,
+            max_bonus: 200,
+            unbonding_period: 86400,
+            slash_share: Decimal::percent(50),
+            voucher_eligibility: VoucherEligibility {
+                min_score: 0,
+                min_stake: Decimal::zero(),
+                min_account_age: 0,
+            },
         },
+        tiers: vec![
+            Tier {
+                name: String::from("New"),
+                min_score: 0,
+            },
+            Tier {
+                name: String::from("Bronze"),
+                min_score: 550,
+            },
+            Tier {
+                name: String::from("Silver"),
+                min_score: 750,
+            },
+            Tier {
+                name: String::from("Gold"),
+                min_score: 1000,
+            },
+        ],
     };
     let trust_addr = router
         .instantiate_contract(trust_id, admin.clone(), &msg, &[], "TRUST", None)
@@ -152,6 +288,125 @@ fn setup_contract() -> App {
     router
 }
 
+fn cw20_stake_source(contract: &str) -> StakeSource {
+    StakeSource::Cw20Stake {
+        contract: Addr::unchecked(contract),
+    }
+}
+
+fn vouching_msg(params: &VouchingParams) -> VouchingParamsMsg {
+    VouchingParamsMsg {
+        token: params.token.to_string(),
+        denom_multiplier: params.denom_multiplier,
+        score_per_token: params.score_per_token,
+        reference_score: params.reference_score,
+        max_bonus: params.max_bonus,
+        unbonding_period: params.unbonding_period,
+        slash_share: params.slash_share,
+        voucher_eligibility: params.voucher_eligibility.clone(),
+    }
+}
+
+// Create a listing, order it and complete the order
+fn complete_order(router: &mut App) {
+    let seller = Addr::unchecked(ADMIN);
+    let buyer = Addr::unchecked(BUYER);
+
+    let create_listing_msg = ExecuteMsg::CreateListing {
+        active: true,
+        price: Uint128::from(1000u128),
+        attributes: Attributes {
+            name: String::from("WHITE TAPED SLEEVE T-SHIRT"),
+            images: vec![String::from(
+                "https://i.ibb.co/Dp3bbDT/image-b98a8387-b183-4339-bb73-609c119be18a-1600x.jpg",
+            )],
+            description: None,
+        },
+        options: vec![],
+    };
+    router
+        .execute_contract(
+            seller.clone(),
+            Addr::unchecked(COMMERCE),
+            &create_listing_msg,
+            &[],
+        )
+        .unwrap();
+
+    let create_order_msg = ReceiveMsg::CreateOrder {
+        items: vec![OrderItem {
+            listing_id: 1,
+            options: vec![],
+            amount: 1,
+        }],
+    };
+    let send_msg = Cw20ExecuteMsg::Send {
+        contract: Addr::unchecked(COMMERCE).to_string(),
+        amount: Uint128::new(1000),
+        msg: to_binary(&create_order_msg).unwrap(),
+    };
+    router
+        .execute_contract(buyer, Addr::unchecked(CW20), &send_msg, &[])
+        .unwrap();
+
+    let update_order_msg = ExecuteMsg::UpdateOrder {
+        id: 1,
+        status: OrderStatus::Shipped,
+        tracking: None,
+    };
+    router
+        .execute_contract(
+            seller.clone(),
+            Addr::unchecked(COMMERCE),
+            &update_order_msg,
+            &[],
+        )
+        .unwrap();
+
+    router
+        .execute_contract(
+            seller,
+            Addr::unchecked(COMMERCE),
+            &ExecuteMsg::CompleteOrder { id: 1 },
+            &[],
+        )
+        .unwrap();
+}
+
+// Have the seller commit to their review of the buyer,
+// so the buyer can leave their review of the seller right away
+fn commit_seller_review(router: &mut App) {
+    let commit_msg = trust::msg::ExecuteMsg::CommitReview {
+        address: BUYER.to_string(),
+        commitment: review_commitment(&Addr::unchecked(BUYER), &ReviewResult::ThumbsUp, "salt"),
+    };
+    router
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            Addr::unchecked(TRUST),
+            &commit_msg,
+            &[],
+        )
+        .unwrap();
+}
+
+fn reveal_seller_review(router: &mut App) {
+    let reveal_msg = trust::msg::ExecuteMsg::RevealReview {
+        address: BUYER.to_string(),
+        review: ReviewResult::ThumbsUp,
+        salt: String::from("salt"),
+        comment: None,
+    };
+    router
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            Addr::unchecked(TRUST),
+            &reveal_msg,
+            &[],
+        )
+        .unwrap();
+}
+
 #[test]
 fn proper_initialization() {
     setup_contract();
@@ -782,10 +1037,14 @@ fn try_complete_order() {
 
     println!("{:?}", res);
 
+    // The seller commits to their review first, so it can't be based on the buyer's
+    commit_seller_review(&mut router);
+
     // Buyer leaves a review to the seller
     let buyer_review_msg = trust::msg::ExecuteMsg::Review {
         address: seller.to_string(),
         review: ReviewResult::ThumbsUp,
+        comment: None,
     };
 
     let res = router.execute_contract(buyer, Addr::unchecked(TRUST), &buyer_review_msg, &[]);
@@ -851,3 +1110,3389 @@ fn try_complete_order() {
 
     println!("{:?}", res);
 }
+
+#[test]
+fn try_review_comment() {
+    let mut router = setup_contract();
+
+    let seller = Addr::unchecked(ADMIN);
+    let buyer = Addr::unchecked(BUYER);
+
+    complete_order(&mut router);
+    commit_seller_review(&mut router);
+
+    // Comment hashes must be hex-encoded SHA-256 hashes
+    let invalid_review_msg = trust::msg::ExecuteMsg::Review {
+        address: seller.to_string(),
+        review: ReviewResult::ThumbsDown,
+        comment: Some(ReviewComment::Anchored {
+            hash: String::from("not-a-hash"),
+            uri: String::from("ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"),
+        }),
+    };
+    let err = router.execute_contract(
+        buyer.clone(),
+        Addr::unchecked(TRUST),
+        &invalid_review_msg,
+        &[],
+    );
+    assert!(err.is_err());
+
+    // Comments can be left out, but not left empty
+    let empty_review_msg = trust::msg::ExecuteMsg::Review {
+        address: seller.to_string(),
+        review: ReviewResult::ThumbsDown,
+        comment: Some(ReviewComment::Text(String::new())),
+    };
+    let err = router.execute_contract(
+        buyer.clone(),
+        Addr::unchecked(TRUST),
+        &empty_review_msg,
+        &[],
+    );
+    assert_eq!(
+        err.unwrap_err().downcast::<trust::ContractError>().unwrap(),
+        trust::ContractError::EmptyComment {}
+    );
+
+    // Only the registered reviewer can leave the review
+    let review_msg = trust::msg::ExecuteMsg::Review {
+        address: seller.to_string(),
+        review: ReviewResult::ThumbsDown,
+        comment: Some(ReviewComment::Text(String::from("Item arrived damaged"))),
+    };
+    let err = router.execute_contract(
+        Addr::unchecked("stranger"),
+        Addr::unchecked(TRUST),
+        &review_msg,
+        &[],
+    );
+    assert!(err.is_err());
+
+    let res = router.execute_contract(buyer.clone(), Addr::unchecked(TRUST), &review_msg, &[]);
+    assert!(res.is_ok());
+    reveal_seller_review(&mut router);
+
+    // The reviewer cannot reply to their own review
+    let reply_msg = trust::msg::ExecuteMsg::ReplyToReview {
+        id: 1,
+        reply: ReviewComment::Text(String::from("Sorry! A replacement is on its way.")),
+    };
+    let err = router.execute_contract(buyer, Addr::unchecked(TRUST), &reply_msg, &[]);
+    assert!(err.is_err());
+
+    let res = router.execute_contract(seller.clone(), Addr::unchecked(TRUST), &reply_msg, &[]);
+    assert!(res.is_ok());
+
+    // Only one reply is allowed per review
+    let err = router.execute_contract(seller.clone(), Addr::unchecked(TRUST), &reply_msg, &[]);
+    assert!(err.is_err());
+
+    let res: trust::response::ReviewsResponse = router
+        .wrap()
+        .query_wasm_smart(
+            Addr::unchecked(TRUST),
+            &trust::msg::QueryMsg::ReviewsByPeer {
+                peer: seller.to_string(),
+                limit: None,
+                start_after: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(res.reviews.len(), 1);
+    assert_eq!(
+        res.reviews[0].comment,
+        Some(ReviewComment::Text(String::from("Item arrived damaged")))
+    );
+    assert_eq!(
+        res.reviews[0].reply,
+        Some(ReviewComment::Text(String::from(
+            "Sorry! A replacement is on its way."
+        )))
+    );
+}
+
+#[test]
+fn try_weighted_review() {
+    let mut router = setup_contract();
+
+    let seller = Addr::unchecked(ADMIN);
+    let buyer = Addr::unchecked(BUYER);
+
+    // The order is worth half the reference order value
+    complete_order(&mut router);
+    commit_seller_review(&mut router);
+
+    let review_msg = trust::msg::ExecuteMsg::Review {
+        address: seller.to_string(),
+        review: ReviewResult::ThumbsUp,
+        comment: None,
+    };
+    let res = router.execute_contract(buyer, Addr::unchecked(TRUST), &review_msg, &[]);
+    assert!(res.is_ok());
+    reveal_seller_review(&mut router);
+
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(
+            Addr::unchecked(TRUST),
+            &trust::msg::QueryMsg::TrustInfo {
+                address: seller.to_string(),
+            },
+        )
+        .unwrap();
+
+    // The buyer has the base score, so the review carries half of its full weight
+    let trust_info = res.trust_info.unwrap();
+    assert_eq!(trust_info.data.rating, 500);
+
+    // Reviews of orders of unknown value carry the minimum weight
+    let legacy_store = Addr::unchecked("legacy-store");
+    router
+        .execute_contract(
+            seller.clone(),
+            Addr::unchecked(TRUST),
+            &trust::msg::ExecuteMsg::AddCommerceContract {
+                address: legacy_store.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    router
+        .execute_contract(
+            legacy_store,
+            Addr::unchecked(TRUST),
+            &trust::msg::ExecuteMsg::RegisterPendingReview {
+                peer: seller.to_string(),
+                reviewer: String::from("legacy-buyer"),
+                order_id: 1,
+                order_value: None,
+                peer_role: Some(TradeRole::Seller),
+            },
+            &[],
+        )
+        .unwrap();
+    router
+        .execute_contract(
+            Addr::unchecked("legacy-buyer"),
+            Addr::unchecked(TRUST),
+            &review_msg,
+            &[],
+        )
+        .unwrap();
+
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(
+            Addr::unchecked(TRUST),
+            &trust::msg::QueryMsg::TrustInfo {
+                address: seller.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.trust_info.unwrap().data.rating, 600);
+}
+
+#[test]
+fn try_rating_decay() {
+    let mut router = setup_contract();
+
+    let seller = Addr::unchecked(ADMIN);
+    let buyer = Addr::unchecked(BUYER);
+
+    complete_order(&mut router);
+    commit_seller_review(&mut router);
+
+    let review_msg = trust::msg::ExecuteMsg::Review {
+        address: seller.to_string(),
+        review: ReviewResult::ThumbsUp,
+        comment: None,
+    };
+    let res = router.execute_contract(buyer, Addr::unchecked(TRUST), &review_msg, &[]);
+    assert!(res.is_ok());
+    reveal_seller_review(&mut router);
+
+    let query_msg = trust::msg::QueryMsg::TrustInfo {
+        address: seller.to_string(),
+    };
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    let fresh_score = res.trust_info.unwrap().score;
+
+    // Half of the half-life later, the review counts 1/sqrt(2) as much
+    router.update_block(|block| block.time = block.time.plus_seconds(1_296_000));
+
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    let trust_info = res.trust_info.unwrap();
+    assert_eq!(trust_info.data.rating, 500);
+    assert_eq!(trust_info.data.decayed_rating, 353);
+
+    // One half-life later, the review counts half as much
+    router.update_block(|block| block.time = block.time.plus_seconds(1_296_000));
+
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    let trust_info = res.trust_info.unwrap();
+    assert_eq!(trust_info.data.rating, 500);
+    assert_eq!(trust_info.data.decayed_rating, 250);
+    assert!(trust_info.score < fresh_score);
+}
+
+#[test]
+fn try_stake_duration() {
+    let mut router = setup_contract();
+
+    let seller = Addr::unchecked(ADMIN);
+    let buyer = Addr::unchecked(BUYER);
+
+    // Create a trust account for the seller
+    complete_order(&mut router);
+    commit_seller_review(&mut router);
+    let review_msg = trust::msg::ExecuteMsg::Review {
+        address: seller.to_string(),
+        review: ReviewResult::ThumbsUp,
+        comment: None,
+    };
+    router
+        .execute_contract(buyer, Addr::unchecked(TRUST), &review_msg, &[])
+        .unwrap();
+
+    // Stake some tokens
+    let send_msg = Cw20ExecuteMsg::Send {
+        contract: Addr::unchecked(CW20_STAKE).to_string(),
+        amount: Uint128::from(100u128),
+        msg: to_binary(&cw20_stake::msg::ReceiveMsg::Stake {}).unwrap(),
+    };
+    router
+        .execute_contract(seller.clone(), Addr::unchecked(CW20), &send_msg, &[])
+        .unwrap();
+
+    let update_staking_msg = trust::msg::ExecuteMsg::UpdateStakingInfo {
+        address: seller.to_string(),
+    };
+    let query_msg = trust::msg::QueryMsg::TrustInfo {
+        address: seller.to_string(),
+    };
+
+    // Syncing several times in a row does not count as several days
+    for _ in 0..3 {
+        router
+            .execute_contract(
+                seller.clone(),
+                Addr::unchecked(TRUST),
+                &update_staking_msg,
+                &[],
+            )
+            .unwrap();
+    }
+
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    assert_eq!(res.trust_info.unwrap().data.stake_days, 0);
+
+    // Stake days are derived from the block time
+    router.update_block(|block| block.time = block.time.plus_seconds(10 * 86400));
+
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    assert_eq!(res.trust_info.unwrap().data.stake_days, 10);
+
+    // Unstaking some tokens resets the timer
+    let unstake_msg = cw20_stake::msg::ExecuteMsg::Unstake {
+        amount: Uint128::from(50u128),
+    };
+    router
+        .execute_contract(
+            seller.clone(),
+            Addr::unchecked(CW20_STAKE),
+            &unstake_msg,
+            &[],
+        )
+        .unwrap();
+    router
+        .execute_contract(seller, Addr::unchecked(TRUST), &update_staking_msg, &[])
+        .unwrap();
+
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    let trust_info = res.trust_info.unwrap();
+    assert_eq!(
+        trust_info.data.stake_amount(&cw20_stake_source(CW20_STAKE)),
+        Uint128::from(50u128)
+    );
+    assert_eq!(trust_info.data.stake_days, 0);
+}
+
+#[test]
+fn try_stake_change_hook() {
+    let mut router = setup_contract();
+
+    let seller = Addr::unchecked(ADMIN);
+    let buyer = Addr::unchecked(BUYER);
+
+    // Create a trust account for the seller
+    complete_order(&mut router);
+    commit_seller_review(&mut router);
+    let review_msg = trust::msg::ExecuteMsg::Review {
+        address: seller.to_string(),
+        review: ReviewResult::ThumbsUp,
+        comment: None,
+    };
+    router
+        .execute_contract(buyer, Addr::unchecked(TRUST), &review_msg, &[])
+        .unwrap();
+    reveal_seller_review(&mut router);
+
+    // Hooks from anything but a staking source are ignored, without failing
+    let hook_msg =
+        trust::msg::ExecuteMsg::StakeChangeHook(cw20_stake::hooks::StakeChangedHookMsg::Stake {
+            addr: seller.clone(),
+            amount: Uint128::from(1_000_000u128),
+        });
+    let res = router
+        .execute_contract(seller.clone(), Addr::unchecked(TRUST), &hook_msg, &[])
+        .unwrap();
+    assert!(res.events.iter().any(|event| event
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "skipped" && attr.value == "unknown_source")));
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(
+            Addr::unchecked(TRUST),
+            &trust::msg::QueryMsg::TrustInfo {
+                address: seller.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(res.trust_info.unwrap().data.stakes.is_empty());
+
+    // Register the trust contract as a stake hook
+    let add_hook_msg = cw20_stake::msg::ExecuteMsg::AddHook {
+        addr: Addr::unchecked(TRUST).to_string(),
+    };
+    router
+        .execute_contract(
+            seller.clone(),
+            Addr::unchecked(CW20_STAKE),
+            &add_hook_msg,
+            &[],
+        )
+        .unwrap();
+
+    // Staking updates the trust account without any maintainer involved
+    let send_msg = Cw20ExecuteMsg::Send {
+        contract: Addr::unchecked(CW20_STAKE).to_string(),
+        amount: Uint128::from(100u128),
+        msg: to_binary(&cw20_stake::msg::ReceiveMsg::Stake {}).unwrap(),
+    };
+    router
+        .execute_contract(seller.clone(), Addr::unchecked(CW20), &send_msg, &[])
+        .unwrap();
+
+    let query_msg = trust::msg::QueryMsg::TrustInfo {
+        address: seller.to_string(),
+    };
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    let trust_info = res.trust_info.unwrap();
+    assert_eq!(
+        trust_info.data.stake_amount(&cw20_stake_source(CW20_STAKE)),
+        Uint128::from(100u128)
+    );
+    let stake_start = trust_info.data.stake_start.unwrap();
+
+    // Unstaking resets the stake timer
+    router.update_block(|block| block.time = block.time.plus_seconds(86400));
+    let unstake_msg = cw20_stake::msg::ExecuteMsg::Unstake {
+        amount: Uint128::from(40u128),
+    };
+    router
+        .execute_contract(seller, Addr::unchecked(CW20_STAKE), &unstake_msg, &[])
+        .unwrap();
+
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    let trust_info = res.trust_info.unwrap();
+    assert_eq!(
+        trust_info.data.stake_amount(&cw20_stake_source(CW20_STAKE)),
+        Uint128::from(60u128)
+    );
+    assert!(trust_info.data.stake_start.unwrap() > stake_start);
+
+    // Stakers without a trust account get one
+    let send_msg = Cw20ExecuteMsg::Send {
+        contract: Addr::unchecked(CW20_STAKE).to_string(),
+        amount: Uint128::from(100u128),
+        msg: to_binary(&cw20_stake::msg::ReceiveMsg::Stake {}).unwrap(),
+    };
+    let res = router.execute_contract(
+        Addr::unchecked(BUYER),
+        Addr::unchecked(CW20),
+        &send_msg,
+        &[],
+    );
+    assert!(res.is_ok());
+
+    let query_msg = trust::msg::QueryMsg::TrustInfo {
+        address: BUYER.to_string(),
+    };
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    let trust_info = res.trust_info.unwrap();
+    assert_eq!(
+        trust_info.data.stake_amount(&cw20_stake_source(CW20_STAKE)),
+        Uint128::from(100u128)
+    );
+}
+
+#[test]
+fn try_permissionless_sync() {
+    let mut router = setup_contract();
+
+    let seller = Addr::unchecked(ADMIN);
+    let buyer = Addr::unchecked(BUYER);
+    let stranger = Addr::unchecked("stranger");
+
+    // Create a trust account for the seller
+    complete_order(&mut router);
+    commit_seller_review(&mut router);
+    let review_msg = trust::msg::ExecuteMsg::Review {
+        address: seller.to_string(),
+        review: ReviewResult::ThumbsUp,
+        comment: None,
+    };
+    router
+        .execute_contract(buyer, Addr::unchecked(TRUST), &review_msg, &[])
+        .unwrap();
+
+    let send_msg = Cw20ExecuteMsg::Send {
+        contract: Addr::unchecked(CW20_STAKE).to_string(),
+        amount: Uint128::from(100u128),
+        msg: to_binary(&cw20_stake::msg::ReceiveMsg::Stake {}).unwrap(),
+    };
+    router
+        .execute_contract(seller.clone(), Addr::unchecked(CW20), &send_msg, &[])
+        .unwrap();
+
+    let update_staking_msg = trust::msg::ExecuteMsg::UpdateStakingInfo {
+        address: seller.to_string(),
+    };
+    let query_msg = trust::msg::QueryMsg::TrustInfo {
+        address: seller.to_string(),
+    };
+
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    assert!(res.stale);
+
+    // Anyone can sync an account
+    let res = router.execute_contract(
+        stranger.clone(),
+        Addr::unchecked(TRUST),
+        &update_staking_msg,
+        &[],
+    );
+    assert!(res.is_ok());
+
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    assert!(!res.stale);
+
+    // ...but not twice within the sync interval
+    let err = router.execute_contract(
+        stranger.clone(),
+        Addr::unchecked(TRUST),
+        &update_staking_msg,
+        &[],
+    );
+    assert!(err.is_err());
+
+    // The maintainer is not rate-limited
+    let res = router.execute_contract(seller, Addr::unchecked(TRUST), &update_staking_msg, &[]);
+    assert!(res.is_ok());
+
+    router.update_block(|block| block.time = block.time.plus_seconds(86400));
+
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    assert!(res.stale);
+
+    let res = router.execute_contract(stranger, Addr::unchecked(TRUST), &update_staking_msg, &[]);
+    assert!(res.is_ok());
+}
+
+#[test]
+fn try_batch_sync() {
+    let mut router = setup_contract();
+
+    let seller = Addr::unchecked(ADMIN);
+    let buyer = Addr::unchecked(BUYER);
+    let stranger = Addr::unchecked("stranger");
+
+    // Create a trust account for the seller
+    complete_order(&mut router);
+    commit_seller_review(&mut router);
+    let review_msg = trust::msg::ExecuteMsg::Review {
+        address: seller.to_string(),
+        review: ReviewResult::ThumbsUp,
+        comment: None,
+    };
+    router
+        .execute_contract(buyer.clone(), Addr::unchecked(TRUST), &review_msg, &[])
+        .unwrap();
+
+    let send_msg = Cw20ExecuteMsg::Send {
+        contract: Addr::unchecked(CW20_STAKE).to_string(),
+        amount: Uint128::from(100u128),
+        msg: to_binary(&cw20_stake::msg::ReceiveMsg::Stake {}).unwrap(),
+    };
+    router
+        .execute_contract(seller.clone(), Addr::unchecked(CW20), &send_msg, &[])
+        .unwrap();
+
+    // The buyer has nothing staked, which doesn't abort the batch
+    let batch_msg = trust::msg::ExecuteMsg::UpdateStakingInfoBatch {
+        addresses: vec![seller.to_string(), buyer.to_string()],
+    };
+    let res = router
+        .execute_contract(stranger.clone(), Addr::unchecked(TRUST), &batch_msg, &[])
+        .unwrap();
+
+    let statuses: Vec<(String, String)> = res
+        .events
+        .iter()
+        .filter(|event| event.ty == "wasm-update_staking_info")
+        .map(|event| {
+            let attr = |key: &str| {
+                event
+                    .attributes
+                    .iter()
+                    .find(|attr| attr.key == key)
+                    .unwrap()
+                    .value
+                    .clone()
+            };
+            (attr("delegator"), attr("status"))
+        })
+        .collect();
+    assert_eq!(
+        statuses,
+        vec![
+            (seller.to_string(), String::from("ok")),
+            (buyer.to_string(), String::from("error")),
+        ]
+    );
+
+    let query_msg = trust::msg::QueryMsg::TrustInfo {
+        address: seller.to_string(),
+    };
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    assert_eq!(
+        res.trust_info
+            .unwrap()
+            .data
+            .stake_amount(&cw20_stake_source(CW20_STAKE)),
+        Uint128::from(100u128)
+    );
+
+    // Syncing next accounts walks all trust accounts, then starts over
+    router.update_block(|block| block.time = block.time.plus_seconds(86400));
+
+    let next_msg = trust::msg::ExecuteMsg::UpdateStakingInfoNext { limit: 1 };
+    let synced = |res: &cw_multi_test::AppResponse| {
+        res.events
+            .iter()
+            .filter(|event| event.ty == "wasm-update_staking_info")
+            .count()
+    };
+
+    let res = router
+        .execute_contract(stranger.clone(), Addr::unchecked(TRUST), &next_msg, &[])
+        .unwrap();
+    assert_eq!(synced(&res), 1);
+
+    let res = router
+        .execute_contract(stranger.clone(), Addr::unchecked(TRUST), &next_msg, &[])
+        .unwrap();
+    assert_eq!(synced(&res), 0);
+
+    router.update_block(|block| block.time = block.time.plus_seconds(86400));
+
+    let res = router
+        .execute_contract(stranger.clone(), Addr::unchecked(TRUST), &next_msg, &[])
+        .unwrap();
+    assert_eq!(synced(&res), 1);
+
+    // Every sender walks the accounts from their own cursor
+    let res = router
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            Addr::unchecked(TRUST),
+            &next_msg,
+            &[],
+        )
+        .unwrap();
+    assert_eq!(synced(&res), 1);
+
+    // A zero limit would not sync anything
+    let err = router.execute_contract(
+        stranger,
+        Addr::unchecked(TRUST),
+        &trust::msg::ExecuteMsg::UpdateStakingInfoNext { limit: 0 },
+        &[],
+    );
+    assert!(err.is_err());
+}
+
+#[test]
+fn try_register() {
+    let mut router = setup_contract();
+
+    let seller = Addr::unchecked(ADMIN);
+    let stranger = Addr::unchecked("stranger");
+
+    let send_msg = Cw20ExecuteMsg::Send {
+        contract: Addr::unchecked(CW20_STAKE).to_string(),
+        amount: Uint128::from(100u128),
+        msg: to_binary(&cw20_stake::msg::ReceiveMsg::Stake {}).unwrap(),
+    };
+    router
+        .execute_contract(seller.clone(), Addr::unchecked(CW20), &send_msg, &[])
+        .unwrap();
+
+    // The first sync creates a trust account for the staker
+    let update_staking_msg = trust::msg::ExecuteMsg::UpdateStakingInfo {
+        address: seller.to_string(),
+    };
+    router
+        .execute_contract(
+            stranger.clone(),
+            Addr::unchecked(TRUST),
+            &update_staking_msg,
+            &[],
+        )
+        .unwrap();
+
+    let query_msg = trust::msg::QueryMsg::TrustInfo {
+        address: seller.to_string(),
+    };
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    let trust_info = res.trust_info.unwrap();
+    assert_eq!(
+        trust_info.data.stake_amount(&cw20_stake_source(CW20_STAKE)),
+        Uint128::from(100u128)
+    );
+    assert_eq!(trust_info.data.rating, 0);
+
+    // Accounts without any stake are not created by syncing
+    let update_staking_msg = trust::msg::ExecuteMsg::UpdateStakingInfo {
+        address: stranger.to_string(),
+    };
+    let err = router.execute_contract(
+        stranger.clone(),
+        Addr::unchecked(TRUST),
+        &update_staking_msg,
+        &[],
+    );
+    assert!(err.is_err());
+
+    // ...but users can opt in themselves
+    let register_msg = trust::msg::ExecuteMsg::Register {};
+    router
+        .execute_contract(stranger.clone(), Addr::unchecked(TRUST), &register_msg, &[])
+        .unwrap();
+
+    let query_msg = trust::msg::QueryMsg::TrustInfo {
+        address: stranger.to_string(),
+    };
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    assert!(res.trust_info.is_some());
+
+    // Only once
+    let err = router.execute_contract(stranger, Addr::unchecked(TRUST), &register_msg, &[]);
+    assert!(err.is_err());
+}
+
+#[test]
+fn try_staking_sources() {
+    let mut router = setup_contract();
+
+    let admin = Addr::unchecked(ADMIN);
+
+    // Set up an LP token and its staking contract as a second staking source
+    let cw20_id = router.store_code(contract_cw20());
+    let msg = cw20_base::msg::InstantiateMsg {
+        name: String::from("Swift LP"),
+        symbol: String::from("SWIFTLP"),
+        decimals: 6,
+        initial_balances: vec![Cw20Coin {
+            address: admin.to_string(),
+            amount: Uint128::new(5000),
+        }],
+        mint: None,
+        marketing: None,
+    };
+    let lp_addr = router
+        .instantiate_contract(cw20_id, admin.clone(), &msg, &[], "SWIFT_LP", None)
+        .unwrap();
+
+    let cw20_stake_id = router.store_code(contract_cw20_stake());
+    let msg = cw20_stake::msg::InstantiateMsg {
+        token_address: lp_addr.to_string(),
+        owner: Some(admin.to_string()),
+        manager: None,
+        unstaking_duration: None,
+    };
+    let lp_stake_addr = router
+        .instantiate_contract(
+            cw20_stake_id,
+            admin.clone(),
+            &msg,
+            &[],
+            "SWIFT_LP_STAKE",
+            None,
+        )
+        .unwrap();
+
+    let res: trust::response::ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &trust::msg::QueryMsg::Config {})
+        .unwrap();
+    let config = res.config;
+
+    // LP tokens count half, and only up to 100 tokens
+    let staking_sources = vec![
+        StakingSourceMsg {
+            source: StakeSourceMsg::Cw20Stake {
+                contract: CW20_STAKE.to_string(),
+            },
+            weight: Decimal::one(),
+            denom_multiplier: 1,
+            max_staked_tokens: Uint128::new(5000),
+        },
+        StakingSourceMsg {
+            source: StakeSourceMsg::Cw20Stake {
+                contract: lp_stake_addr.to_string(),
+            },
+            weight: Decimal::percent(50),
+            denom_multiplier: 1,
+            max_staked_tokens: Uint128::new(100),
+        },
+    ];
+    let update_config_msg = trust::msg::ExecuteMsg::UpdateConfig {
+        admin: config.admin.to_string(),
+        maintainer: config.maintainer.map(|maintainer| maintainer.to_string()),
+        staking_sources: staking_sources.clone(),
+        review_interval: config.review_interval,
+        max_staked_days: config.max_staked_days,
+        min_sync_interval: config.min_sync_interval,
+        max_rating: config.max_rating,
+        rating_half_life: config.rating_half_life,
+        score_model: config.score_model.clone(),
+    };
+
+    // Only the admin can update the config
+    let err = router.execute_contract(
+        Addr::unchecked(BUYER),
+        Addr::unchecked(TRUST),
+        &update_config_msg,
+        &[],
+    );
+    assert!(err.is_err());
+
+    router
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(TRUST),
+            &update_config_msg,
+            &[],
+        )
+        .unwrap();
+
+    // Stake in both sources
+    for (token, staking_contract, amount) in [
+        (Addr::unchecked(CW20), Addr::unchecked(CW20_STAKE), 100u128),
+        (lp_addr, lp_stake_addr.clone(), 300u128),
+    ] {
+        let send_msg = Cw20ExecuteMsg::Send {
+            contract: staking_contract.to_string(),
+            amount: Uint128::from(amount),
+            msg: to_binary(&cw20_stake::msg::ReceiveMsg::Stake {}).unwrap(),
+        };
+        router
+            .execute_contract(admin.clone(), token, &send_msg, &[])
+            .unwrap();
+    }
+
+    let query_msg = trust::msg::QueryMsg::StakeAmount {
+        address: admin.to_string(),
+    };
+    let res: trust::response::StakeAmountResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    assert_eq!(res.stake_amount, Decimal::from_ratio(150u128, 1u128));
+    assert_eq!(res.sources.len(), 2);
+    assert_eq!(
+        res.sources[1].source,
+        cw20_stake_source(lp_stake_addr.as_str())
+    );
+    assert_eq!(res.sources[1].stake_amount, Some(Uint128::new(300)));
+    assert_eq!(
+        res.sources[1].weighted_amount,
+        Decimal::from_ratio(50u128, 1u128)
+    );
+
+    // Syncing records the stake in every source
+    let update_staking_msg = trust::msg::ExecuteMsg::UpdateStakingInfo {
+        address: admin.to_string(),
+    };
+    router
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(TRUST),
+            &update_staking_msg,
+            &[],
+        )
+        .unwrap();
+
+    let query_msg = trust::msg::QueryMsg::TrustInfo {
+        address: admin.to_string(),
+    };
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    let trust_info = res.trust_info.unwrap();
+    assert_eq!(
+        trust_info.data.stake_amount(&cw20_stake_source(CW20_STAKE)),
+        Uint128::new(100)
+    );
+    assert_eq!(
+        trust_info
+            .data
+            .stake_amount(&cw20_stake_source(lp_stake_addr.as_str())),
+        Uint128::new(300)
+    );
+
+    // Staking sources must be unique
+    let update_config_msg = trust::msg::ExecuteMsg::UpdateConfig {
+        admin: config.admin.to_string(),
+        maintainer: None,
+        staking_sources: vec![staking_sources[0].clone(), staking_sources[0].clone()],
+        review_interval: config.review_interval,
+        max_staked_days: config.max_staked_days,
+        min_sync_interval: config.min_sync_interval,
+        max_rating: config.max_rating,
+        rating_half_life: config.rating_half_life,
+        score_model: config.score_model,
+    };
+    let err = router.execute_contract(admin, Addr::unchecked(TRUST), &update_config_msg, &[]);
+    assert!(err.is_err());
+}
+
+#[test]
+fn try_native_staking() {
+    let mut router = setup_contract();
+
+    let admin = Addr::unchecked(ADMIN);
+
+    let res: trust::response::ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &trust::msg::QueryMsg::Config {})
+        .unwrap();
+    let config = res.config;
+
+    // Native delegations count double, and only up to 500 tokens
+    let update_config_msg = trust::msg::ExecuteMsg::UpdateConfig {
+        admin: config.admin.to_string(),
+        maintainer: config.maintainer.map(|maintainer| maintainer.to_string()),
+        staking_sources: vec![
+            StakingSourceMsg {
+                source: StakeSourceMsg::Cw20Stake {
+                    contract: CW20_STAKE.to_string(),
+                },
+                weight: Decimal::one(),
+                denom_multiplier: 1,
+                max_staked_tokens: Uint128::new(5000),
+            },
+            StakingSourceMsg {
+                source: StakeSourceMsg::Native {
+                    denom: String::from("ujuno"),
+                },
+                weight: Decimal::percent(200),
+                denom_multiplier: 1,
+                max_staked_tokens: Uint128::new(500),
+            },
+        ],
+        review_interval: config.review_interval,
+        max_staked_days: config.max_staked_days,
+        min_sync_interval: config.min_sync_interval,
+        max_rating: config.max_rating,
+        rating_half_life: config.rating_half_life,
+        score_model: config.score_model,
+    };
+    router
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(TRUST),
+            &update_config_msg,
+            &[],
+        )
+        .unwrap();
+
+    let delegate_msg = CosmosMsg::Staking(StakingMsg::Delegate {
+        validator: String::from(VALIDATOR),
+        amount: coin(300, "ujuno"),
+    });
+    router.execute(admin.clone(), delegate_msg).unwrap();
+
+    let native_source = StakeSource::Native {
+        denom: String::from("ujuno"),
+    };
+
+    let query_msg = trust::msg::QueryMsg::StakeAmount {
+        address: admin.to_string(),
+    };
+    let res: trust::response::StakeAmountResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    assert_eq!(res.sources[1].source, native_source);
+    assert_eq!(res.sources[1].stake_amount, Some(Uint128::new(300)));
+    assert_eq!(
+        res.sources[1].weighted_amount,
+        Decimal::from_ratio(600u128, 1u128)
+    );
+
+    // Delegations above the cap are not taken into consideration
+    let delegate_msg = CosmosMsg::Staking(StakingMsg::Delegate {
+        validator: String::from(VALIDATOR),
+        amount: coin(300, "ujuno"),
+    });
+    router.execute(admin.clone(), delegate_msg).unwrap();
+
+    let res: trust::response::StakeAmountResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    assert_eq!(res.sources[1].stake_amount, Some(Uint128::new(600)));
+    assert_eq!(res.stake_amount, Decimal::from_ratio(1000u128, 1u128));
+
+    // Syncing picks up native delegations
+    let update_staking_msg = trust::msg::ExecuteMsg::UpdateStakingInfo {
+        address: admin.to_string(),
+    };
+    router
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(TRUST),
+            &update_staking_msg,
+            &[],
+        )
+        .unwrap();
+
+    let query_msg = trust::msg::QueryMsg::TrustInfo {
+        address: admin.to_string(),
+    };
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    let trust_info = res.trust_info.unwrap();
+    assert_eq!(
+        trust_info.data.stake_amount(&native_source),
+        Uint128::new(600)
+    );
+    assert!(trust_info.data.stake_start.is_some());
+}
+
+#[test]
+fn try_score_models() {
+    let mut router = setup_contract();
+
+    let seller = Addr::unchecked(ADMIN);
+    let buyer = Addr::unchecked(BUYER);
+
+    // Half-weight thumbs up, as the order is worth half the reference value
+    complete_order(&mut router);
+    commit_seller_review(&mut router);
+    let review_msg = trust::msg::ExecuteMsg::Review {
+        address: seller.to_string(),
+        review: ReviewResult::ThumbsUp,
+        comment: None,
+    };
+    router
+        .execute_contract(buyer, Addr::unchecked(TRUST), &review_msg, &[])
+        .unwrap();
+    reveal_seller_review(&mut router);
+
+    let update_score_model = |router: &mut App, score_model: ScoreModel| {
+        let res: trust::response::ConfigResponse = router
+            .wrap()
+            .query_wasm_smart(Addr::unchecked(TRUST), &trust::msg::QueryMsg::Config {})
+            .unwrap();
+        let config = res.config;
+
+        let update_config_msg = trust::msg::ExecuteMsg::UpdateConfig {
+            admin: config.admin.to_string(),
+            maintainer: config.maintainer.map(|maintainer| maintainer.to_string()),
+            staking_sources: vec![StakingSourceMsg {
+                source: StakeSourceMsg::Cw20Stake {
+                    contract: CW20_STAKE.to_string(),
+                },
+                weight: Decimal::one(),
+                denom_multiplier: 1,
+                max_staked_tokens: Uint128::new(5000),
+            }],
+            review_interval: config.review_interval,
+            max_staked_days: config.max_staked_days,
+            min_sync_interval: config.min_sync_interval,
+            max_rating: config.max_rating,
+            rating_half_life: 0,
+            score_model,
+        };
+        router.execute_contract(
+            Addr::unchecked(ADMIN),
+            Addr::unchecked(TRUST),
+            &update_config_msg,
+            &[],
+        )
+    };
+    let query_score = |router: &mut App| {
+        let query_msg = trust::msg::QueryMsg::TrustInfo {
+            address: ADMIN.to_string(),
+        };
+        let res: trust::response::TrustInfoResponse = router
+            .wrap()
+            .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+            .unwrap();
+        res.trust_info.unwrap().score
+    };
+
+    // 500 + 25 * 0.5 - 1 * (0.5 / 10)
+    assert_eq!(
+        query_score(&mut router),
+        Decimal::from_ratio(51245u128, 100u128)
+    );
+
+    update_score_model(
+        &mut router,
+        ScoreModel::LogStake(LogStakeModelParams {
+            base_score: 500,
+            rating_multiplier: 25,
+            stake_multiplier: 10,
+            stake_amount_denominator: 10,
+            max_score: 1500,
+        }),
+    )
+    .unwrap();
+    assert_eq!(
+        query_score(&mut router),
+        Decimal::from_ratio(5125u128, 10u128)
+    );
+
+    // Stake 70 tokens for a day
+    let send_msg = Cw20ExecuteMsg::Send {
+        contract: Addr::unchecked(CW20_STAKE).to_string(),
+        amount: Uint128::from(70u128),
+        msg: to_binary(&cw20_stake::msg::ReceiveMsg::Stake {}).unwrap(),
+    };
+    router
+        .execute_contract(seller.clone(), Addr::unchecked(CW20), &send_msg, &[])
+        .unwrap();
+    let update_staking_msg = trust::msg::ExecuteMsg::UpdateStakingInfo {
+        address: seller.to_string(),
+    };
+    router
+        .execute_contract(seller, Addr::unchecked(TRUST), &update_staking_msg, &[])
+        .unwrap();
+    router.update_block(|block| block.time = block.time.plus_seconds(86400));
+
+    // 500 + 25 * 0.5 + 10 * log2(1 + 70 * 1 / 10)
+    assert_eq!(
+        query_score(&mut router),
+        Decimal::from_ratio(5425u128, 10u128)
+    );
+
+    update_score_model(
+        &mut router,
+        ScoreModel::BayesianRating(BayesianRatingModelParams {
+            base_score: 500,
+            rating_range: 100,
+            prior_weight: 2,
+            prior_share: Decimal::percent(50),
+            stake_amount_denominator: 10,
+            max_score: 1000,
+        }),
+    )
+    .unwrap();
+
+    // 500 + 100 * (2 * 0.6 - 1) + 70 / 10 * 1
+    // The share of positive reviews is (2 * 0.5 + 0.5) / (2 + 0.5)
+    assert_eq!(
+        query_score(&mut router),
+        Decimal::from_ratio(527u128, 1u128)
+    );
+
+    // Models that would divide by zero or give shares above one are rejected
+    let err = update_score_model(
+        &mut router,
+        ScoreModel::LogStake(LogStakeModelParams {
+            base_score: 500,
+            rating_multiplier: 25,
+            stake_multiplier: 10,
+            stake_amount_denominator: 0,
+            max_score: 1500,
+        }),
+    );
+    assert!(err.is_err());
+    let err = update_score_model(
+        &mut router,
+        ScoreModel::BayesianRating(BayesianRatingModelParams {
+            base_score: 500,
+            rating_range: 100,
+            prior_weight: 2,
+            prior_share: Decimal::percent(150),
+            stake_amount_denominator: 10,
+            max_score: 1000,
+        }),
+    );
+    assert!(err.is_err());
+}
+
+#[test]
+fn try_score_breakdown() {
+    let mut router = setup_contract();
+
+    let seller = Addr::unchecked(ADMIN);
+    let buyer = Addr::unchecked(BUYER);
+
+    let query_msg = trust::msg::QueryMsg::ScoreBreakdown {
+        address: seller.to_string(),
+    };
+    let res: trust::response::ScoreBreakdownResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    assert!(res.breakdown.is_none());
+
+    // Half-weight thumbs up, as the order is worth half the reference value
+    complete_order(&mut router);
+    commit_seller_review(&mut router);
+    let review_msg = trust::msg::ExecuteMsg::Review {
+        address: seller.to_string(),
+        review: ReviewResult::ThumbsUp,
+        comment: None,
+    };
+    router
+        .execute_contract(buyer, Addr::unchecked(TRUST), &review_msg, &[])
+        .unwrap();
+    reveal_seller_review(&mut router);
+
+    let res: trust::response::ScoreBreakdownResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    let breakdown = res.breakdown.unwrap();
+    assert_eq!(breakdown.base.value, Decimal::from_ratio(500u128, 1u128));
+    assert_eq!(breakdown.rating.value, Decimal::from_ratio(125u128, 10u128));
+    assert!(!breakdown.rating.negative);
+    assert_eq!(breakdown.stake.value, Decimal::zero());
+    assert_eq!(
+        breakdown.rating_floor.value,
+        Decimal::from_ratio(5u128, 100u128)
+    );
+    assert!(breakdown.rating_floor.negative);
+    assert_eq!(breakdown.max_score, Decimal::from_ratio(1500u128, 1u128));
+    assert_eq!(breakdown.score, Decimal::from_ratio(51245u128, 100u128));
+
+    // What if the seller staked 100 tokens for 10 days?
+    let query_msg = trust::msg::QueryMsg::TrustInfo {
+        address: seller.to_string(),
+    };
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    let data = TrustData {
+        stake_days: 10,
+        stakes: vec![SourceStake {
+            source: cw20_stake_source(CW20_STAKE),
+            amount: Uint128::new(100),
+        }],
+        ..res.trust_info.unwrap().data
+    };
+
+    let query_msg = trust::msg::QueryMsg::SimulateScore {
+        data: Box::new(data.clone()),
+    };
+    let res: trust::response::SimulateScoreResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    assert_eq!(
+        res.breakdown.stake.value,
+        Decimal::from_ratio(100u128, 1u128)
+    );
+    assert_eq!(res.breakdown.score, Decimal::from_ratio(61245u128, 100u128));
+
+    // Out of range bonuses are capped rather than failing the simulation
+    let attestation = |attester: &str| trust::state::Attestation {
+        attester: Addr::unchecked(attester),
+        kind: String::from("kyc"),
+        uri: None,
+        score_bonus: u64::MAX,
+        issued_at: router.block_info().time,
+        expires_at: None,
+    };
+    let data = TrustData {
+        attestations: vec![
+            attestation("first-attester"),
+            attestation("second-attester"),
+        ],
+        vouched_amount: Decimal::MAX,
+        ..data
+    };
+    let query_msg = trust::msg::QueryMsg::SimulateScore {
+        data: Box::new(data),
+    };
+    let res: trust::response::SimulateScoreResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    assert_eq!(res.breakdown.score, Decimal::from_ratio(1500u128, 1u128));
+}
+
+#[test]
+fn try_leaderboard() {
+    let mut router = setup_contract();
+
+    let seller = Addr::unchecked(ADMIN);
+    let buyer = Addr::unchecked(BUYER);
+    let stranger = Addr::unchecked("stranger");
+
+    complete_order(&mut router);
+    commit_seller_review(&mut router);
+    let review_msg = trust::msg::ExecuteMsg::Review {
+        address: seller.to_string(),
+        review: ReviewResult::ThumbsUp,
+        comment: None,
+    };
+    router
+        .execute_contract(buyer.clone(), Addr::unchecked(TRUST), &review_msg, &[])
+        .unwrap();
+    reveal_seller_review(&mut router);
+
+    let passerby = Addr::unchecked("passerby");
+    let register_msg = trust::msg::ExecuteMsg::Register {};
+    for account in [passerby.clone(), stranger.clone()] {
+        router
+            .execute_contract(account, Addr::unchecked(TRUST), &register_msg, &[])
+            .unwrap();
+    }
+
+    // Ties are ranked by address
+    let query_msg = trust::msg::QueryMsg::TopAccounts {
+        limit: None,
+        start_after: None,
+    };
+    let res: trust::response::TopAccountsResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    let accounts: Vec<Addr> = res
+        .accounts
+        .iter()
+        .map(|account| account.address.clone())
+        .collect();
+    assert_eq!(
+        accounts,
+        vec![buyer.clone(), seller.clone(), stranger.clone(), passerby]
+    );
+    assert_eq!(
+        res.accounts[1].score,
+        Decimal::from_ratio(51245u128, 100u128)
+    );
+
+    let query_msg = trust::msg::QueryMsg::TopAccounts {
+        limit: Some(1),
+        start_after: Some(seller.to_string()),
+    };
+    let res: trust::response::TopAccountsResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    assert_eq!(res.accounts.len(), 1);
+    assert_eq!(res.accounts[0].address, stranger);
+
+    let query_msg = trust::msg::QueryMsg::Rank {
+        address: stranger.to_string(),
+    };
+    let res: trust::response::RankResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    assert_eq!(res.rank, Some(3));
+    assert!(!res.capped);
+
+    let query_msg = trust::msg::QueryMsg::Rank {
+        address: String::from("nobody"),
+    };
+    let res: trust::response::RankResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+        .unwrap();
+    assert_eq!(res.rank, None);
+}
+
+#[test]
+fn try_trust_info_at_height() {
+    let mut router = setup_contract();
+
+    let seller = Addr::unchecked(ADMIN);
+    let buyer = Addr::unchecked(BUYER);
+
+    complete_order(&mut router);
+    commit_seller_review(&mut router);
+    let order_height = router.block_info().height;
+
+    router.update_block(|block| block.height += 1);
+
+    let review_msg = trust::msg::ExecuteMsg::Review {
+        address: seller.to_string(),
+        review: ReviewResult::ThumbsUp,
+        comment: None,
+    };
+    router
+        .execute_contract(buyer, Addr::unchecked(TRUST), &review_msg, &[])
+        .unwrap();
+    reveal_seller_review(&mut router);
+    let review_height = router.block_info().height;
+
+    router.update_block(|block| block.height += 1);
+
+    let query_at = |router: &App, height: u64| {
+        let query_msg = trust::msg::QueryMsg::TrustInfoAtHeight {
+            address: seller.to_string(),
+            height,
+        };
+        let res: trust::response::TrustInfoAtHeightResponse = router
+            .wrap()
+            .query_wasm_smart(Addr::unchecked(TRUST), &query_msg)
+            .unwrap();
+        res.trust_info
+    };
+
+    // No trust account when the order was placed, nor at the start of the review's block
+    assert!(query_at(&router, order_height).is_none());
+    assert!(query_at(&router, review_height).is_none());
+
+    let trust_info = query_at(&router, review_height + 1).unwrap();
+    assert_eq!(trust_info.data.rating, 500);
+}
+
+#[test]
+fn try_migrate_legacy() {
+    let mut router = App::default();
+
+    let admin = Addr::unchecked(ADMIN);
+    let seller = Addr::unchecked("seller");
+
+    let legacy_id = router.store_code(contract_legacy_trust());
+    let trust_id = router.store_code(contract_trust());
+
+    let legacy_config = LegacyConfig {
+        admin: admin.clone(),
+        maintainer: admin.clone(),
+        staking_contract: Addr::unchecked(CW20_STAKE),
+        commerce_code_id: 3,
+        review_interval: 86400,
+        max_staked_tokens: Uint128::new(5000),
+        max_staked_days: 365,
+        max_rating: 1000,
+        trust_score_params: LegacyTrustScoreParams {
+            base_score: 500,
+            rating_multiplier: 25,
+            stake_amount_denominator: 250,
+            min_stake_days: 14,
+            rating_floor_denominator: 10,
+            denom_multiplier: 1,
+        },
+    };
+    let legacy_trust_info = LegacyTrustInfo {
+        score: Decimal::from_ratio(5592u128, 10u128),
+        data: LegacyTrustData {
+            stake_days: 3,
+            stake_amount: Uint128::new(1000),
+            prev_stake_amount: Uint128::new(1000),
+            rating: 2,
+        },
+    };
+    let legacy_pending_review = LegacyPendingReview {
+        peer: seller.clone(),
+        reviewer: Addr::unchecked(BUYER),
+        commerce_contract: Addr::unchecked(COMMERCE),
+        order_id: 1,
+        expires_at: router.block_info().time.plus_seconds(86400),
+    };
+    let legacy_addr = router
+        .instantiate_contract(
+            legacy_id,
+            admin.clone(),
+            &(
+                legacy_config,
+                vec![(seller.clone(), legacy_trust_info)],
+                vec![legacy_pending_review],
+            ),
+            &[],
+            "TRUST",
+            Some(admin.to_string()),
+        )
+        .unwrap();
+
+    // Settings the first release didn't have are required to migrate
+    let err = router.migrate_contract(
+        admin.clone(),
+        legacy_addr.clone(),
+        &MigrateMsg { vouching: None },
+        trust_id,
+    );
+    assert!(err.is_err());
+
+    let migrate_msg = MigrateMsg {
+        vouching: Some(VouchingParamsMsg {
+            token: CW20.to_string(),
+            denom_multiplier: 1,
+            score_per_token: Decimal::percent(10),
+            reference_score: 500,
+            max_bonus: 200,
+            unbonding_period: 86400,
+            slash_share: Decimal::percent(50),
+            voucher_eligibility: VoucherEligibility {
+                min_score: 0,
+                min_stake: Decimal::zero(),
+                min_account_age: 0,
+            },
+        }),
+    };
+    router
+        .migrate_contract(admin, legacy_addr.clone(), &migrate_msg, trust_id)
+        .unwrap();
+
+    let res: trust::response::ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(legacy_addr.clone(), &trust::msg::QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(
+        res.config.staking_sources[0].source,
+        cw20_stake_source(CW20_STAKE)
+    );
+    assert_eq!(
+        res.config.staking_sources[0].max_staked_tokens,
+        Uint128::new(5000)
+    );
+
+    // The commerce code ID is still approved
+    let res: trust::response::CommerceCodeIdsResponse = router
+        .wrap()
+        .query_wasm_smart(
+            legacy_addr.clone(),
+            &trust::msg::QueryMsg::CommerceCodeIds {},
+        )
+        .unwrap();
+    assert_eq!(res.code_ids, vec![3]);
+
+    // The account keeps its score, and is ranked
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(
+            legacy_addr.clone(),
+            &trust::msg::QueryMsg::TrustInfo {
+                address: seller.to_string(),
+            },
+        )
+        .unwrap();
+    let trust_info = res.trust_info.unwrap();
+    assert_eq!(trust_info.score, Decimal::from_ratio(5592u128, 10u128));
+    assert_eq!(trust_info.data.rating, 2000);
+    assert_eq!(trust_info.data.stake_days, 3);
+    assert_eq!(
+        trust_info.data.stake_amount(&cw20_stake_source(CW20_STAKE)),
+        Uint128::new(1000)
+    );
+
+    let res: trust::response::RankResponse = router
+        .wrap()
+        .query_wasm_smart(
+            legacy_addr.clone(),
+            &trust::msg::QueryMsg::Rank {
+                address: seller.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.rank, Some(1));
+
+    // Pending reviews are kept, with an unknown order value and role
+    let res: trust::response::PendingReviewResponse = router
+        .wrap()
+        .query_wasm_smart(
+            legacy_addr.clone(),
+            &trust::msg::QueryMsg::PendingReview {
+                peer: seller.to_string(),
+            },
+        )
+        .unwrap();
+    let pending_review = res.pending_review.unwrap();
+    assert_eq!(pending_review.reviewer, Addr::unchecked(BUYER));
+    assert_eq!(pending_review.order_value, None);
+    assert_eq!(pending_review.peer_role, TradeRole::Unknown);
+
+    // and can still be left
+    router
+        .execute_contract(
+            Addr::unchecked(BUYER),
+            legacy_addr.clone(),
+            &trust::msg::ExecuteMsg::Review {
+                address: seller.to_string(),
+                review: ReviewResult::ThumbsUp,
+                comment: None,
+            },
+            &[],
+        )
+        .unwrap();
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(
+            legacy_addr,
+            &trust::msg::QueryMsg::TrustInfo {
+                address: seller.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.trust_info.unwrap().data.rating, 3000);
+}
+
+#[test]
+fn try_commerce_registry() {
+    let mut router = setup_contract();
+
+    let admin = Addr::unchecked(ADMIN);
+    let commerce = Addr::unchecked(COMMERCE);
+
+    let commerce_code_id = router
+        .wrap()
+        .query_wasm_contract_info(COMMERCE)
+        .unwrap()
+        .code_id;
+
+    let res: trust::response::CommerceCodeIdsResponse = router
+        .wrap()
+        .query_wasm_smart(
+            Addr::unchecked(TRUST),
+            &trust::msg::QueryMsg::CommerceCodeIds {},
+        )
+        .unwrap();
+    assert_eq!(res.code_ids, vec![commerce_code_id]);
+
+    // Pending reviews are unique per reviewer
+    let register_msg = |peer: &str| trust::msg::ExecuteMsg::RegisterPendingReview {
+        peer: peer.to_string(),
+        reviewer: format!("{}-reviewer", peer),
+        order_id: 1,
+        order_value: Some(Uint128::new(1000)),
+        peer_role: Some(TradeRole::Seller),
+    };
+
+    // Contracts with an approved code ID can register pending reviews
+    router
+        .execute_contract(
+            commerce.clone(),
+            Addr::unchecked(TRUST),
+            &register_msg("peer1"),
+            &[],
+        )
+        .unwrap();
+
+    // Only the admin can manage the registry
+    let remove_code_id_msg = trust::msg::ExecuteMsg::RemoveCommerceCodeId {
+        code_id: commerce_code_id,
+    };
+    let err = router.execute_contract(
+        Addr::unchecked(BUYER),
+        Addr::unchecked(TRUST),
+        &remove_code_id_msg,
+        &[],
+    );
+    assert!(err.is_err());
+    router
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(TRUST),
+            &remove_code_id_msg,
+            &[],
+        )
+        .unwrap();
+
+    let err = router.execute_contract(
+        commerce.clone(),
+        Addr::unchecked(TRUST),
+        &register_msg("peer2"),
+        &[],
+    );
+    assert!(err.is_err());
+
+    // Contracts can also be approved by address, whatever their code ID
+    let add_contract_msg = trust::msg::ExecuteMsg::AddCommerceContract {
+        address: commerce.to_string(),
+    };
+    router
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(TRUST),
+            &add_contract_msg,
+            &[],
+        )
+        .unwrap();
+
+    let res: trust::response::CommerceContractsResponse = router
+        .wrap()
+        .query_wasm_smart(
+            Addr::unchecked(TRUST),
+            &trust::msg::QueryMsg::CommerceContracts {},
+        )
+        .unwrap();
+    assert_eq!(res.contracts, vec![commerce.clone()]);
+
+    router
+        .execute_contract(
+            commerce.clone(),
+            Addr::unchecked(TRUST),
+            &register_msg("peer2"),
+            &[],
+        )
+        .unwrap();
+
+    let remove_contract_msg = trust::msg::ExecuteMsg::RemoveCommerceContract {
+        address: commerce.to_string(),
+    };
+    router
+        .execute_contract(admin, Addr::unchecked(TRUST), &remove_contract_msg, &[])
+        .unwrap();
+
+    let err = router.execute_contract(
+        commerce,
+        Addr::unchecked(TRUST),
+        &register_msg("peer3"),
+        &[],
+    );
+    assert!(err.is_err());
+}
+
+#[test]
+fn try_reviewer_eligibility() {
+    let mut router = setup_contract();
+
+    let seller = Addr::unchecked(ADMIN);
+    let buyer = Addr::unchecked(BUYER);
+
+    complete_order(&mut router);
+    commit_seller_review(&mut router);
+
+    let update_eligibility = |router: &mut App, reviewer_eligibility: ReviewerEligibility| {
+        let res: trust::response::ConfigResponse = router
+            .wrap()
+            .query_wasm_smart(Addr::unchecked(TRUST), &trust::msg::QueryMsg::Config {})
+            .unwrap();
+        let config = res.config;
+
+        let update_review_params_msg = trust::msg::ExecuteMsg::UpdateReviewParams {
+            review_weight: config.review_weight,
+            reviewer_eligibility,
+            pair_review_limit: config.pair_review_limit,
+            appeal_window: config.appeal_window,
+        };
+        router
+            .execute_contract(
+                Addr::unchecked(ADMIN),
+                Addr::unchecked(TRUST),
+                &update_review_params_msg,
+                &[],
+            )
+            .unwrap();
+    };
+
+    let review_msg = trust::msg::ExecuteMsg::Review {
+        address: seller.to_string(),
+        review: ReviewResult::ThumbsDown,
+        comment: None,
+    };
+
+    // The buyer has no trust account, so it is too young to review
+    let reviewer_eligibility = ReviewerEligibility {
+        min_score: 0,
+        min_stake: Decimal::zero(),
+        min_account_age: 86400,
+        min_order_value: Uint128::zero(),
+        ineligible_reviews: IneligibleReviewPolicy::Reject,
+    };
+    update_eligibility(&mut router, reviewer_eligibility.clone());
+    let err = router.execute_contract(buyer.clone(), Addr::unchecked(TRUST), &review_msg, &[]);
+    assert!(err.is_err());
+
+    // The order is too small, whatever the account age
+    update_eligibility(
+        &mut router,
+        ReviewerEligibility {
+            min_account_age: 0,
+            min_order_value: Uint128::new(1001),
+            ..reviewer_eligibility.clone()
+        },
+    );
+    let err = router.execute_contract(buyer.clone(), Addr::unchecked(TRUST), &review_msg, &[]);
+    assert!(err.is_err());
+
+    // The requirements are checked against the current score, not the stored one
+    let attester = Addr::unchecked("kyc-provider");
+    router
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            Addr::unchecked(TRUST),
+            &trust::msg::ExecuteMsg::AddAttester {
+                address: attester.to_string(),
+                name: String::from("KYC provider"),
+                score_bonus: 50,
+            },
+            &[],
+        )
+        .unwrap();
+    router
+        .execute_contract(
+            attester,
+            Addr::unchecked(TRUST),
+            &trust::msg::ExecuteMsg::Attest {
+                address: buyer.to_string(),
+                kind: String::from("kyc"),
+                uri: None,
+                expires_at: Some(router.block_info().time.plus_seconds(3600)),
+            },
+            &[],
+        )
+        .unwrap();
+    update_eligibility(
+        &mut router,
+        ReviewerEligibility {
+            min_score: 550,
+            min_account_age: 0,
+            ..reviewer_eligibility.clone()
+        },
+    );
+    router.update_block(|block| block.time = block.time.plus_seconds(3600));
+    let err = router.execute_contract(buyer.clone(), Addr::unchecked(TRUST), &review_msg, &[]);
+    assert_eq!(
+        err.unwrap_err().downcast::<trust::ContractError>().unwrap(),
+        trust::ContractError::IneligibleReviewer {
+            requirement: String::from("min_score: 550"),
+        }
+    );
+
+    // Ineligible reviews can be recorded without moving the rating
+    update_eligibility(
+        &mut router,
+        ReviewerEligibility {
+            ineligible_reviews: IneligibleReviewPolicy::ZeroWeight,
+            ..reviewer_eligibility
+        },
+    );
+    router
+        .execute_contract(buyer.clone(), Addr::unchecked(TRUST), &review_msg, &[])
+        .unwrap();
+    reveal_seller_review(&mut router);
+
+    let res: trust::response::ReviewsResponse = router
+        .wrap()
+        .query_wasm_smart(
+            Addr::unchecked(TRUST),
+            &trust::msg::QueryMsg::ReviewsByReviewer {
+                reviewer: buyer.to_string(),
+                limit: None,
+                start_after: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.reviews.len(), 1);
+    assert_eq!(res.reviews[0].rating_delta, 0);
+
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(
+            Addr::unchecked(TRUST),
+            &trust::msg::QueryMsg::TrustInfo {
+                address: seller.to_string(),
+            },
+        )
+        .unwrap();
+    let trust_info = res.trust_info.unwrap();
+    assert_eq!(trust_info.data.rating, 0);
+    assert_eq!(trust_info.score, Decimal::from_ratio(500u128, 1u128));
+}
+
+#[test]
+fn try_pair_review_limit() {
+    let mut router = setup_contract();
+
+    let seller = Addr::unchecked(ADMIN);
+    let buyer = Addr::unchecked(BUYER);
+
+    let res: trust::response::ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &trust::msg::QueryMsg::Config {})
+        .unwrap();
+    let config = res.config;
+
+    // A reviewer can move a peer's rating by one and a half reviews a day
+    let update_config_msg = trust::msg::ExecuteMsg::UpdateConfig {
+        admin: config.admin.to_string(),
+        maintainer: config.maintainer.map(|maintainer| maintainer.to_string()),
+        staking_sources: vec![StakingSourceMsg {
+            source: StakeSourceMsg::Cw20Stake {
+                contract: CW20_STAKE.to_string(),
+            },
+            weight: Decimal::one(),
+            denom_multiplier: 1,
+            max_staked_tokens: Uint128::new(5000),
+        }],
+        review_interval: config.review_interval,
+        max_staked_days: config.max_staked_days,
+        min_sync_interval: config.min_sync_interval,
+        max_rating: config.max_rating,
+        rating_half_life: 0,
+        score_model: config.score_model,
+    };
+    router
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            Addr::unchecked(TRUST),
+            &update_config_msg,
+            &[],
+        )
+        .unwrap();
+    let update_review_params_msg = trust::msg::ExecuteMsg::UpdateReviewParams {
+        review_weight: config.review_weight,
+        reviewer_eligibility: config.reviewer_eligibility,
+        pair_review_limit: PairReviewLimit {
+            window: 86400,
+            max_weight: Decimal::percent(150),
+        },
+        appeal_window: config.appeal_window,
+    };
+    router
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            Addr::unchecked(TRUST),
+            &update_review_params_msg,
+            &[],
+        )
+        .unwrap();
+
+    // Full-weight thumbs-up from the same buyer
+    let review = |router: &mut App, order_id: u64| {
+        router
+            .execute_contract(
+                Addr::unchecked(COMMERCE),
+                Addr::unchecked(TRUST),
+                &trust::msg::ExecuteMsg::RegisterPendingReview {
+                    peer: seller.to_string(),
+                    reviewer: buyer.to_string(),
+                    order_id,
+                    order_value: Some(Uint128::new(2000)),
+                    peer_role: Some(TradeRole::Seller),
+                },
+                &[],
+            )
+            .unwrap();
+        router
+            .execute_contract(
+                buyer.clone(),
+                Addr::unchecked(TRUST),
+                &trust::msg::ExecuteMsg::Review {
+                    address: seller.to_string(),
+                    review: ReviewResult::ThumbsUp,
+                    comment: None,
+                },
+                &[],
+            )
+            .unwrap();
+    };
+    let query_rating = |router: &mut App| {
+        let res: trust::response::TrustInfoResponse = router
+            .wrap()
+            .query_wasm_smart(
+                Addr::unchecked(TRUST),
+                &trust::msg::QueryMsg::TrustInfo {
+                    address: seller.to_string(),
+                },
+            )
+            .unwrap();
+        res.trust_info.unwrap().data.rating
+    };
+
+    review(&mut router, 1);
+    assert_eq!(query_rating(&mut router), 1000);
+
+    // Only half of the second review fits within the cap
+    review(&mut router, 2);
+    assert_eq!(query_rating(&mut router), 1500);
+
+    review(&mut router, 3);
+    assert_eq!(query_rating(&mut router), 1500);
+
+    let res: trust::response::PairReviewCounterResponse = router
+        .wrap()
+        .query_wasm_smart(
+            Addr::unchecked(TRUST),
+            &trust::msg::QueryMsg::PairReviewCounter {
+                peer: seller.to_string(),
+                reviewer: buyer.to_string(),
+            },
+        )
+        .unwrap();
+    let counter = res.counter.unwrap();
+    assert_eq!(counter.reviews, 3);
+    assert_eq!(counter.weight, 1500);
+    assert_eq!(counter.total_reviews, 3);
+
+    // The cap is lifted once the window ends
+    router.update_block(|block| block.time = block.time.plus_seconds(86400));
+    review(&mut router, 4);
+    assert_eq!(query_rating(&mut router), 2500);
+
+    let res: trust::response::PairReviewCountersResponse = router
+        .wrap()
+        .query_wasm_smart(
+            Addr::unchecked(TRUST),
+            &trust::msg::QueryMsg::PairReviewCounters {
+                peer: seller.to_string(),
+                limit: None,
+                start_after: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.counters.len(), 1);
+    assert_eq!(res.counters[0].reviewer, buyer);
+    assert_eq!(res.counters[0].counter.reviews, 1);
+    assert_eq!(res.counters[0].counter.weight, 1000);
+    assert_eq!(res.counters[0].counter.total_reviews, 4);
+}
+
+#[test]
+fn try_commit_reveal_review() {
+    let mut router = setup_contract();
+
+    let seller = Addr::unchecked(ADMIN);
+    let buyer = Addr::unchecked(BUYER);
+
+    // The buyer and the seller now review each other
+    complete_order(&mut router);
+
+    // Neither can leave their review directly before the other commits to theirs
+    let err = router.execute_contract(
+        seller.clone(),
+        Addr::unchecked(TRUST),
+        &trust::msg::ExecuteMsg::Review {
+            address: buyer.to_string(),
+            review: ReviewResult::ThumbsDown,
+            comment: None,
+        },
+        &[],
+    );
+    assert!(err.is_err());
+
+    let commit_msg = trust::msg::ExecuteMsg::CommitReview {
+        address: seller.to_string(),
+        commitment: review_commitment(&seller, &ReviewResult::ThumbsDown, "buyer-salt"),
+    };
+    router
+        .execute_contract(buyer.clone(), Addr::unchecked(TRUST), &commit_msg, &[])
+        .unwrap();
+
+    // Committed reviews can't be left directly nor committed to twice
+    let review_msg = trust::msg::ExecuteMsg::Review {
+        address: seller.to_string(),
+        review: ReviewResult::ThumbsUp,
+        comment: None,
+    };
+    let err = router.execute_contract(buyer.clone(), Addr::unchecked(TRUST), &review_msg, &[]);
+    assert!(err.is_err());
+    let err = router.execute_contract(buyer.clone(), Addr::unchecked(TRUST), &commit_msg, &[]);
+    assert!(err.is_err());
+
+    // The buyer can't reveal before the seller commits
+    let reveal_msg = trust::msg::ExecuteMsg::RevealReview {
+        address: seller.to_string(),
+        review: ReviewResult::ThumbsDown,
+        salt: String::from("buyer-salt"),
+        comment: None,
+    };
+    let err = router.execute_contract(buyer.clone(), Addr::unchecked(TRUST), &reveal_msg, &[]);
+    assert!(err.is_err());
+
+    let commit_msg = trust::msg::ExecuteMsg::CommitReview {
+        address: buyer.to_string(),
+        commitment: review_commitment(&buyer, &ReviewResult::ThumbsDown, "seller-salt"),
+    };
+    router
+        .execute_contract(seller.clone(), Addr::unchecked(TRUST), &commit_msg, &[])
+        .unwrap();
+
+    // The revealed review must match the commitment
+    let wrong_reveal_msg = trust::msg::ExecuteMsg::RevealReview {
+        address: seller.to_string(),
+        review: ReviewResult::ThumbsUp,
+        salt: String::from("buyer-salt"),
+        comment: None,
+    };
+    let err = router.execute_contract(
+        buyer.clone(),
+        Addr::unchecked(TRUST),
+        &wrong_reveal_msg,
+        &[],
+    );
+    assert!(err.is_err());
+
+    router
+        .execute_contract(buyer.clone(), Addr::unchecked(TRUST), &reveal_msg, &[])
+        .unwrap();
+
+    let query_rating = |router: &mut App, address: &Addr| {
+        let res: trust::response::TrustInfoResponse = router
+            .wrap()
+            .query_wasm_smart(
+                Addr::unchecked(TRUST),
+                &trust::msg::QueryMsg::TrustInfo {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap();
+        res.trust_info.map(|trust_info| trust_info.data.rating)
+    };
+
+    // The revealed review is held back until the seller reveals theirs,
+    // so the seller can't decide whether to reveal based on it
+    assert_eq!(query_rating(&mut router, &seller), None);
+    let err = router.execute_contract(buyer.clone(), Addr::unchecked(TRUST), &reveal_msg, &[]);
+    assert!(err.is_err());
+
+    let stranger = Addr::unchecked("stranger");
+    let settle_msg = trust::msg::ExecuteMsg::SettleReview {
+        address: seller.to_string(),
+    };
+    let err = router.execute_contract(stranger.clone(), Addr::unchecked(TRUST), &settle_msg, &[]);
+    assert_eq!(
+        err.unwrap_err().downcast::<trust::ContractError>().unwrap(),
+        trust::ContractError::AwaitingReveal {
+            counterpart: seller.to_string(),
+        }
+    );
+
+    // The seller doesn't reveal in time, so the buyer isn't reviewed
+    // and the buyer's review can be settled on its own
+    router.update_block(|block| block.time = block.time.plus_seconds(2 * 86400));
+    let reveal_msg = trust::msg::ExecuteMsg::RevealReview {
+        address: buyer.to_string(),
+        review: ReviewResult::ThumbsDown,
+        salt: String::from("seller-salt"),
+        comment: None,
+    };
+    let err = router.execute_contract(seller.clone(), Addr::unchecked(TRUST), &reveal_msg, &[]);
+    assert!(err.is_err());
+
+    router
+        .execute_contract(stranger.clone(), Addr::unchecked(TRUST), &settle_msg, &[])
+        .unwrap();
+    assert_eq!(query_rating(&mut router, &seller), Some(-500));
+    assert_eq!(query_rating(&mut router, &buyer), None);
+
+    let err = router.execute_contract(stranger, Addr::unchecked(TRUST), &settle_msg, &[]);
+    assert!(err.is_err());
+}
+
+#[test]
+fn try_appeal_review() {
+    let mut router = setup_contract();
+
+    let seller = Addr::unchecked(ADMIN);
+    let buyer = Addr::unchecked(BUYER);
+
+    complete_order(&mut router);
+    commit_seller_review(&mut router);
+
+    let review_msg = trust::msg::ExecuteMsg::Review {
+        address: seller.to_string(),
+        review: ReviewResult::ThumbsDown,
+        comment: None,
+    };
+    router
+        .execute_contract(buyer.clone(), Addr::unchecked(TRUST), &review_msg, &[])
+        .unwrap();
+    let reveal_msg = trust::msg::ExecuteMsg::RevealReview {
+        address: buyer.to_string(),
+        review: ReviewResult::ThumbsUp,
+        salt: String::from("salt"),
+        comment: None,
+    };
+    router
+        .execute_contract(seller.clone(), Addr::unchecked(TRUST), &reveal_msg, &[])
+        .unwrap();
+
+    let query_rating = |router: &mut App| {
+        let res: trust::response::TrustInfoResponse = router
+            .wrap()
+            .query_wasm_smart(
+                Addr::unchecked(TRUST),
+                &trust::msg::QueryMsg::TrustInfo {
+                    address: seller.to_string(),
+                },
+            )
+            .unwrap();
+        res.trust_info.unwrap().data.rating
+    };
+    assert_eq!(query_rating(&mut router), -500);
+
+    // Only the reviewed peer can appeal, and only negative reviews
+    let appeal_msg = trust::msg::ExecuteMsg::AppealReview {
+        id: 1,
+        reason: Some(ReviewComment::Text(String::from(
+            "The item was as described",
+        ))),
+    };
+    let err = router.execute_contract(buyer.clone(), Addr::unchecked(TRUST), &appeal_msg, &[]);
+    assert!(err.is_err());
+    let err = router.execute_contract(
+        buyer.clone(),
+        Addr::unchecked(TRUST),
+        &trust::msg::ExecuteMsg::AppealReview {
+            id: 2,
+            reason: None,
+        },
+        &[],
+    );
+    assert!(err.is_err());
+
+    router
+        .execute_contract(seller.clone(), Addr::unchecked(TRUST), &appeal_msg, &[])
+        .unwrap();
+    let err = router.execute_contract(seller.clone(), Addr::unchecked(TRUST), &appeal_msg, &[]);
+    assert!(err.is_err());
+
+    let res: trust::response::AppealsResponse = router
+        .wrap()
+        .query_wasm_smart(
+            Addr::unchecked(TRUST),
+            &trust::msg::QueryMsg::Appeals {
+                status: Some(AppealStatus::Pending),
+                limit: None,
+                start_after: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.appeals.len(), 1);
+    assert_eq!(res.appeals[0].review_id, 1);
+    assert_eq!(res.appeals[0].rating_delta, -500);
+
+    // The review doesn't count while the appeal is pending
+    assert_eq!(query_rating(&mut router), 0);
+
+    // Only the admin can decide on appeals
+    let decide_msg = trust::msg::ExecuteMsg::DecideAppeal {
+        id: 1,
+        decision: AppealDecision::Strike,
+    };
+    let err = router.execute_contract(buyer, Addr::unchecked(TRUST), &decide_msg, &[]);
+    assert!(err.is_err());
+
+    router
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            Addr::unchecked(TRUST),
+            &decide_msg,
+            &[],
+        )
+        .unwrap();
+    assert_eq!(query_rating(&mut router), 0);
+
+    // Decisions are final
+    let err = router.execute_contract(
+        Addr::unchecked(ADMIN),
+        Addr::unchecked(TRUST),
+        &decide_msg,
+        &[],
+    );
+    assert!(err.is_err());
+
+    let res: trust::response::AppealsResponse = router
+        .wrap()
+        .query_wasm_smart(
+            Addr::unchecked(TRUST),
+            &trust::msg::QueryMsg::AppealsByPeer {
+                peer: seller.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.appeals.len(), 1);
+    assert_eq!(res.appeals[0].status, AppealStatus::Struck);
+    assert!(res.appeals[0].decided_at.is_some());
+}
+
+#[test]
+fn try_attestations() {
+    let mut router = setup_contract();
+
+    let attester = Addr::unchecked("kyc-provider");
+    let subject = Addr::unchecked("subject");
+
+    let attest_msg = |kind: &str, expires_at| trust::msg::ExecuteMsg::Attest {
+        address: subject.to_string(),
+        kind: kind.to_string(),
+        uri: None,
+        expires_at,
+    };
+    let expires_at = mock_env().block.time.plus_seconds(86400);
+
+    // Only approved attesters can attest
+    let err = router.execute_contract(
+        attester.clone(),
+        Addr::unchecked(TRUST),
+        &attest_msg("kyc", Some(expires_at)),
+        &[],
+    );
+    assert!(err.is_err());
+
+    // Only the admin can approve attesters
+    let add_attester_msg = trust::msg::ExecuteMsg::AddAttester {
+        address: attester.to_string(),
+        name: String::from("KYC provider"),
+        score_bonus: 50,
+    };
+    let err = router.execute_contract(
+        Addr::unchecked(BUYER),
+        Addr::unchecked(TRUST),
+        &add_attester_msg,
+        &[],
+    );
+    assert!(err.is_err());
+    router
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            Addr::unchecked(TRUST),
+            &add_attester_msg,
+            &[],
+        )
+        .unwrap();
+
+    let res: trust::response::AttestersResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &trust::msg::QueryMsg::Attesters {})
+        .unwrap();
+    assert_eq!(res.attesters.len(), 1);
+    assert_eq!(res.attesters[0].address, attester);
+
+    // Attestations from the same attester only add their bonus once
+    router
+        .execute_contract(
+            attester.clone(),
+            Addr::unchecked(TRUST),
+            &attest_msg("kyc", Some(expires_at)),
+            &[],
+        )
+        .unwrap();
+    router
+        .execute_contract(
+            attester.clone(),
+            Addr::unchecked(TRUST),
+            &attest_msg("business_registry", None),
+            &[],
+        )
+        .unwrap();
+
+    let query_trust_info = |router: &mut App| {
+        let res: trust::response::TrustInfoResponse = router
+            .wrap()
+            .query_wasm_smart(
+                Addr::unchecked(TRUST),
+                &trust::msg::QueryMsg::TrustInfo {
+                    address: subject.to_string(),
+                },
+            )
+            .unwrap();
+        res.trust_info.unwrap()
+    };
+    let trust_info = query_trust_info(&mut router);
+    assert_eq!(trust_info.data.attestations.len(), 2);
+    assert_eq!(trust_info.score, Decimal::from_ratio(550u128, 1u128));
+
+    // Expired attestations are dropped
+    router.update_block(|block| block.time = block.time.plus_seconds(86400));
+    let trust_info = query_trust_info(&mut router);
+    assert_eq!(trust_info.data.attestations.len(), 1);
+    assert_eq!(trust_info.data.attestations[0].kind, "business_registry");
+
+    // Attesters can only revoke their own attestations
+    let revoke_msg = trust::msg::ExecuteMsg::RevokeAttestation {
+        address: subject.to_string(),
+        kind: String::from("business_registry"),
+        attester: Some(attester.to_string()),
+    };
+    let err = router.execute_contract(
+        Addr::unchecked(BUYER),
+        Addr::unchecked(TRUST),
+        &revoke_msg,
+        &[],
+    );
+    assert!(err.is_err());
+    router
+        .execute_contract(attester.clone(), Addr::unchecked(TRUST), &revoke_msg, &[])
+        .unwrap();
+
+    let trust_info = query_trust_info(&mut router);
+    assert!(trust_info.data.attestations.is_empty());
+    assert_eq!(trust_info.score, Decimal::from_ratio(500u128, 1u128));
+
+    // Removing an attester drops the attestations it issued
+    router
+        .execute_contract(
+            attester.clone(),
+            Addr::unchecked(TRUST),
+            &attest_msg("business_registry", None),
+            &[],
+        )
+        .unwrap();
+    assert_eq!(
+        query_trust_info(&mut router).score,
+        Decimal::from_ratio(550u128, 1u128)
+    );
+    router
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            Addr::unchecked(TRUST),
+            &trust::msg::ExecuteMsg::RemoveAttester {
+                address: attester.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let trust_info = query_trust_info(&mut router);
+    assert!(trust_info.data.attestations.is_empty());
+    assert_eq!(trust_info.score, Decimal::from_ratio(500u128, 1u128));
+}
+
+#[test]
+fn try_vouching() {
+    let mut router = setup_contract();
+
+    let seller = Addr::unchecked(ADMIN);
+    let buyer = Addr::unchecked(BUYER);
+    let voucher = Addr::unchecked("voucher");
+
+    router
+        .execute_contract(
+            buyer.clone(),
+            Addr::unchecked(CW20),
+            &Cw20ExecuteMsg::Transfer {
+                recipient: voucher.to_string(),
+                amount: Uint128::new(2000),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let vouch_msg = |address: &Addr, amount: u128| Cw20ExecuteMsg::Send {
+        contract: String::from(TRUST),
+        amount: Uint128::new(amount),
+        msg: to_binary(&trust::msg::ReceiveMsg::Vouch {
+            address: address.to_string(),
+        })
+        .unwrap(),
+    };
+
+    // Nobody can vouch for themselves
+    let err = router.execute_contract(
+        voucher.clone(),
+        Addr::unchecked(CW20),
+        &vouch_msg(&voucher, 1000),
+        &[],
+    );
+    assert!(err.is_err());
+
+    complete_order(&mut router);
+    commit_seller_review(&mut router);
+    let review_msg = trust::msg::ExecuteMsg::Review {
+        address: seller.to_string(),
+        review: ReviewResult::ThumbsDown,
+        comment: None,
+    };
+    router
+        .execute_contract(buyer.clone(), Addr::unchecked(TRUST), &review_msg, &[])
+        .unwrap();
+    reveal_seller_review(&mut router);
+
+    let query_breakdown = |router: &mut App| {
+        let res: trust::response::ScoreBreakdownResponse = router
+            .wrap()
+            .query_wasm_smart(
+                Addr::unchecked(TRUST),
+                &trust::msg::QueryMsg::ScoreBreakdown {
+                    address: seller.to_string(),
+                },
+            )
+            .unwrap();
+        res.breakdown.unwrap().vouches.value
+    };
+    assert!(query_breakdown(&mut router).is_zero());
+
+    // A voucher without a trust account vouches with the weight of an empty account
+    router
+        .execute_contract(
+            voucher.clone(),
+            Addr::unchecked(CW20),
+            &vouch_msg(&seller, 1000),
+            &[],
+        )
+        .unwrap();
+    assert_eq!(
+        query_breakdown(&mut router),
+        Decimal::from_ratio(100u128, 1u128)
+    );
+
+    // The vouching token can't change while tokens are vouched
+    let res: trust::response::ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &trust::msg::QueryMsg::Config {})
+        .unwrap();
+    let config = res.config;
+    let update_vouching_params_msg = trust::msg::ExecuteMsg::UpdateVouchingParams {
+        vouching: VouchingParamsMsg {
+            token: CW20_STAKE.to_string(),
+            ..vouching_msg(&config.vouching)
+        },
+    };
+    let err = router.execute_contract(
+        seller.clone(),
+        Addr::unchecked(TRUST),
+        &update_vouching_params_msg,
+        &[],
+    );
+    assert!(err.is_err());
+
+    let res: trust::response::VouchesResponse = router
+        .wrap()
+        .query_wasm_smart(
+            Addr::unchecked(TRUST),
+            &trust::msg::QueryMsg::VouchesReceived {
+                vouchee: seller.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.vouches.len(), 1);
+    assert_eq!(res.vouches[0].voucher, voucher);
+    assert_eq!(res.vouches[0].amount, Uint128::new(1000));
+
+    // Unvouched tokens stay locked until the unbonding period ends
+    router
+        .execute_contract(
+            voucher.clone(),
+            Addr::unchecked(TRUST),
+            &trust::msg::ExecuteMsg::Unvouch {
+                address: seller.to_string(),
+                amount: Some(Uint128::new(400)),
+            },
+            &[],
+        )
+        .unwrap();
+    assert_eq!(
+        query_breakdown(&mut router),
+        Decimal::from_ratio(60u128, 1u128)
+    );
+    let claim_msg = trust::msg::ExecuteMsg::ClaimUnvouched {};
+    let err = router.execute_contract(voucher.clone(), Addr::unchecked(TRUST), &claim_msg, &[]);
+    assert!(err.is_err());
+
+    // Losing a dispute doesn't slash anything on its own
+    router
+        .execute_contract(
+            seller.clone(),
+            Addr::unchecked(TRUST),
+            &trust::msg::ExecuteMsg::AppealReview {
+                id: 1,
+                reason: None,
+            },
+            &[],
+        )
+        .unwrap();
+    let query_rating = |router: &mut App| {
+        let res: trust::response::TrustInfoResponse = router
+            .wrap()
+            .query_wasm_smart(
+                Addr::unchecked(TRUST),
+                &trust::msg::QueryMsg::TrustInfo {
+                    address: seller.to_string(),
+                },
+            )
+            .unwrap();
+        res.trust_info.unwrap().data.rating
+    };
+    assert_eq!(query_rating(&mut router), 0);
+    router
+        .execute_contract(
+            seller.clone(),
+            Addr::unchecked(TRUST),
+            &trust::msg::ExecuteMsg::DecideAppeal {
+                id: 1,
+                decision: AppealDecision::Uphold,
+            },
+            &[],
+        )
+        .unwrap();
+    assert_eq!(
+        query_breakdown(&mut router),
+        Decimal::from_ratio(60u128, 1u128)
+    );
+
+    // The upheld review counts again
+    assert_eq!(query_rating(&mut router), -500);
+
+    // Only the admin can slash vouched and unbonding tokens
+    let slash_msg = trust::msg::ExecuteMsg::Slash {
+        address: seller.to_string(),
+        start_after: None,
+        limit: None,
+    };
+    let err = router.execute_contract(buyer.clone(), Addr::unchecked(TRUST), &slash_msg, &[]);
+    assert!(err.is_err());
+
+    let admin_balance = Cw20Contract(Addr::unchecked(CW20))
+        .balance::<_, _, Empty>(&router, seller.clone())
+        .unwrap();
+    router
+        .execute_contract(seller.clone(), Addr::unchecked(TRUST), &slash_msg, &[])
+        .unwrap();
+    assert_eq!(
+        query_breakdown(&mut router),
+        Decimal::from_ratio(30u128, 1u128)
+    );
+
+    let res: trust::response::UnbondingsResponse = router
+        .wrap()
+        .query_wasm_smart(
+            Addr::unchecked(TRUST),
+            &trust::msg::QueryMsg::Unbondings {
+                voucher: voucher.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.unbondings.len(), 1);
+    assert_eq!(res.unbondings[0].amount, Uint128::new(200));
+
+    let slashed = Cw20Contract(Addr::unchecked(CW20))
+        .balance::<_, _, Empty>(&router, seller.clone())
+        .unwrap()
+        - admin_balance;
+    assert_eq!(slashed, Uint128::new(500));
+
+    router.update_block(|block| block.time = block.time.plus_seconds(86400));
+    router
+        .execute_contract(voucher.clone(), Addr::unchecked(TRUST), &claim_msg, &[])
+        .unwrap();
+    let voucher_balance = Cw20Contract(Addr::unchecked(CW20))
+        .balance::<_, _, Empty>(&router, voucher.clone())
+        .unwrap();
+    assert_eq!(voucher_balance, Uint128::new(1200));
+
+    let err = router.execute_contract(voucher.clone(), Addr::unchecked(TRUST), &claim_msg, &[]);
+    assert!(err.is_err());
+
+    // Unvouching again while tokens are unbonding merges them into one unbonding
+    let unvouch_msg = trust::msg::ExecuteMsg::Unvouch {
+        address: seller.to_string(),
+        amount: Some(Uint128::new(100)),
+    };
+    for _ in 0..2 {
+        router
+            .execute_contract(voucher.clone(), Addr::unchecked(TRUST), &unvouch_msg, &[])
+            .unwrap();
+    }
+    let res: trust::response::UnbondingsResponse = router
+        .wrap()
+        .query_wasm_smart(
+            Addr::unchecked(TRUST),
+            &trust::msg::QueryMsg::Unbondings {
+                voucher: voucher.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.unbondings.len(), 1);
+    assert_eq!(res.unbondings[0].amount, Uint128::new(200));
+
+    // Vouchers are slashed a page at a time, whatever the amount of them
+    router
+        .execute_contract(
+            buyer.clone(),
+            Addr::unchecked(CW20),
+            &vouch_msg(&seller, 100),
+            &[],
+        )
+        .unwrap();
+    let slash_page_msg = |start_after: Option<&Addr>| trust::msg::ExecuteMsg::Slash {
+        address: seller.to_string(),
+        start_after: start_after.map(|voucher| voucher.to_string()),
+        limit: Some(1),
+    };
+    let admin_balance = Cw20Contract(Addr::unchecked(CW20))
+        .balance::<_, _, Empty>(&router, seller.clone())
+        .unwrap();
+    let res = router
+        .execute_contract(
+            seller.clone(),
+            Addr::unchecked(TRUST),
+            &slash_page_msg(None),
+            &[],
+        )
+        .unwrap();
+    assert!(res.events.iter().any(|event| event
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "last_voucher" && attr.value == buyer.as_str())));
+    router
+        .execute_contract(
+            seller.clone(),
+            Addr::unchecked(TRUST),
+            &slash_page_msg(Some(&buyer)),
+            &[],
+        )
+        .unwrap();
+    let slashed = Cw20Contract(Addr::unchecked(CW20))
+        .balance::<_, _, Empty>(&router, seller.clone())
+        .unwrap()
+        - admin_balance;
+    assert_eq!(slashed, Uint128::new(50 + 150));
+
+    let err = router.execute_contract(
+        seller.clone(),
+        Addr::unchecked(TRUST),
+        &slash_page_msg(Some(&voucher)),
+        &[],
+    );
+    assert!(err.is_err());
+
+    // Only established accounts can vouch
+    let res: trust::response::ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &trust::msg::QueryMsg::Config {})
+        .unwrap();
+    let update_vouching_params_msg = trust::msg::ExecuteMsg::UpdateVouchingParams {
+        vouching: VouchingParamsMsg {
+            voucher_eligibility: VoucherEligibility {
+                min_score: 550,
+                min_stake: Decimal::zero(),
+                min_account_age: 0,
+            },
+            ..vouching_msg(&res.config.vouching)
+        },
+    };
+    router
+        .execute_contract(
+            seller.clone(),
+            Addr::unchecked(TRUST),
+            &update_vouching_params_msg,
+            &[],
+        )
+        .unwrap();
+
+    let newcomer = Addr::unchecked("newcomer");
+    router
+        .execute_contract(
+            buyer,
+            Addr::unchecked(CW20),
+            &Cw20ExecuteMsg::Transfer {
+                recipient: newcomer.to_string(),
+                amount: Uint128::new(100),
+            },
+            &[],
+        )
+        .unwrap();
+    let err = router.execute_contract(
+        newcomer.clone(),
+        Addr::unchecked(CW20),
+        &vouch_msg(&seller, 10),
+        &[],
+    );
+    assert!(err.is_err());
+
+    let attester = Addr::unchecked("kyc-provider");
+    router
+        .execute_contract(
+            seller.clone(),
+            Addr::unchecked(TRUST),
+            &trust::msg::ExecuteMsg::AddAttester {
+                address: attester.to_string(),
+                name: String::from("KYC provider"),
+                score_bonus: 50,
+            },
+            &[],
+        )
+        .unwrap();
+    router
+        .execute_contract(
+            attester,
+            Addr::unchecked(TRUST),
+            &trust::msg::ExecuteMsg::Attest {
+                address: newcomer.to_string(),
+                kind: String::from("kyc"),
+                uri: None,
+                expires_at: Some(router.block_info().time.plus_seconds(86400)),
+            },
+            &[],
+        )
+        .unwrap();
+    router
+        .execute_contract(
+            newcomer.clone(),
+            Addr::unchecked(CW20),
+            &vouch_msg(&seller, 10),
+            &[],
+        )
+        .unwrap();
+
+    // Requirements are checked against the current score, not the stored one
+    router.update_block(|block| block.time = block.time.plus_seconds(86400));
+    let err = router.execute_contract(
+        newcomer,
+        Addr::unchecked(CW20),
+        &vouch_msg(&seller, 10),
+        &[],
+    );
+    assert!(err.is_err());
+}
+
+#[test]
+fn try_bans() {
+    let mut router = setup_contract();
+
+    let seller = Addr::unchecked(ADMIN);
+    let buyer = Addr::unchecked(BUYER);
+
+    complete_order(&mut router);
+
+    // Only the admin can ban
+    let ban_msg = |address: &Addr, expires_at| trust::msg::ExecuteMsg::Ban {
+        address: address.to_string(),
+        reason: String::from("Chargeback fraud"),
+        expires_at,
+    };
+    let expires_at = mock_env().block.time.plus_seconds(86400);
+    let err = router.execute_contract(
+        buyer.clone(),
+        Addr::unchecked(TRUST),
+        &ban_msg(&seller, None),
+        &[],
+    );
+    assert!(err.is_err());
+
+    // A ban needs a reason
+    let err = router
+        .execute_contract(
+            seller.clone(),
+            Addr::unchecked(TRUST),
+            &trust::msg::ExecuteMsg::Ban {
+                address: buyer.to_string(),
+                reason: String::new(),
+                expires_at: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<trust::ContractError>().unwrap(),
+        trust::ContractError::InvalidReason {}
+    );
+
+    router
+        .execute_contract(
+            seller.clone(),
+            Addr::unchecked(TRUST),
+            &ban_msg(&buyer, Some(expires_at)),
+            &[],
+        )
+        .unwrap();
+
+    let query_ban = |router: &mut App, address: &Addr| {
+        let res: trust::response::BanResponse = router
+            .wrap()
+            .query_wasm_smart(
+                Addr::unchecked(TRUST),
+                &trust::msg::QueryMsg::Ban {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap();
+        res
+    };
+    let query_score = |router: &mut App, address: &Addr| {
+        let res: trust::response::TrustInfoResponse = router
+            .wrap()
+            .query_wasm_smart(
+                Addr::unchecked(TRUST),
+                &trust::msg::QueryMsg::TrustInfo {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap();
+        res.trust_info.unwrap().score
+    };
+    let res = query_ban(&mut router, &buyer);
+    assert!(res.active);
+    assert_eq!(res.ban.unwrap().expires_at, Some(expires_at));
+    assert!(query_score(&mut router, &buyer).is_zero());
+
+    // Banned addresses can neither review nor be reviewed
+    let review_msg = |address: &Addr| trust::msg::ExecuteMsg::Review {
+        address: address.to_string(),
+        review: ReviewResult::ThumbsUp,
+        comment: None,
+    };
+    let err = router.execute_contract(
+        buyer.clone(),
+        Addr::unchecked(TRUST),
+        &review_msg(&seller),
+        &[],
+    );
+    assert!(err.is_err());
+    let err = router.execute_contract(
+        seller.clone(),
+        Addr::unchecked(TRUST),
+        &review_msg(&buyer),
+        &[],
+    );
+    assert!(err.is_err());
+
+    // Banned buyers cannot order
+    let send_msg = Cw20ExecuteMsg::Send {
+        contract: Addr::unchecked(COMMERCE).to_string(),
+        amount: Uint128::new(1000),
+        msg: to_binary(&ReceiveMsg::CreateOrder {
+            items: vec![OrderItem {
+                listing_id: 1,
+                options: vec![],
+                amount: 1,
+            }],
+        })
+        .unwrap(),
+    };
+    let err = router.execute_contract(buyer.clone(), Addr::unchecked(CW20), &send_msg, &[]);
+    assert!(err.is_err());
+
+    // Lifting the ban restores the score and removes it from the registry
+    router
+        .execute_contract(
+            seller.clone(),
+            Addr::unchecked(TRUST),
+            &trust::msg::ExecuteMsg::Unban {
+                address: buyer.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    assert!(query_ban(&mut router, &buyer).ban.is_none());
+    assert_eq!(
+        query_score(&mut router, &buyer),
+        Decimal::from_ratio(500u128, 1u128)
+    );
+    commit_seller_review(&mut router);
+    router
+        .execute_contract(
+            buyer.clone(),
+            Addr::unchecked(TRUST),
+            &review_msg(&seller),
+            &[],
+        )
+        .unwrap();
+
+    // Buyers cannot order from banned sellers
+    router
+        .execute_contract(buyer.clone(), Addr::unchecked(CW20), &send_msg, &[])
+        .unwrap();
+    router
+        .execute_contract(
+            seller.clone(),
+            Addr::unchecked(COMMERCE),
+            &ExecuteMsg::UpdateOrder {
+                id: 2,
+                status: OrderStatus::Shipped,
+                tracking: None,
+            },
+            &[],
+        )
+        .unwrap();
+    router
+        .execute_contract(
+            seller.clone(),
+            Addr::unchecked(TRUST),
+            &ban_msg(&seller, None),
+            &[],
+        )
+        .unwrap();
+    let err = router.execute_contract(buyer.clone(), Addr::unchecked(CW20), &send_msg, &[]);
+    assert!(err.is_err());
+
+    // Nor can their orders be completed, only refunded
+    let err = router
+        .execute_contract(
+            seller.clone(),
+            Addr::unchecked(COMMERCE),
+            &ExecuteMsg::CompleteOrder { id: 2 },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<crate::ContractError>().unwrap(),
+        crate::ContractError::Banned {
+            address: seller.to_string(),
+        }
+    );
+    router
+        .execute_contract(
+            seller.clone(),
+            Addr::unchecked(COMMERCE),
+            &ExecuteMsg::RefundOrder { id: 2 },
+            &[],
+        )
+        .unwrap();
+
+    // Expired bans stay in the registry until lifted
+    let stranger = Addr::unchecked("stranger");
+    router
+        .execute_contract(
+            seller,
+            Addr::unchecked(TRUST),
+            &ban_msg(&stranger, Some(expires_at)),
+            &[],
+        )
+        .unwrap();
+    router.update_block(|block| block.time = block.time.plus_seconds(86400));
+    assert!(!query_ban(&mut router, &stranger).active);
+    assert_eq!(
+        query_score(&mut router, &stranger),
+        Decimal::from_ratio(500u128, 1u128)
+    );
+
+    let res: trust::response::BansResponse = router
+        .wrap()
+        .query_wasm_smart(
+            Addr::unchecked(TRUST),
+            &trust::msg::QueryMsg::Bans {
+                limit: None,
+                start_after: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.bans.len(), 2);
+}
+
+#[test]
+fn try_tiers() {
+    let mut router = setup_contract();
+
+    let subject = Addr::unchecked("subject");
+
+    let query_tier = |router: &mut App| {
+        let res: trust::response::TierResponse = router
+            .wrap()
+            .query_wasm_smart(
+                Addr::unchecked(TRUST),
+                &trust::msg::QueryMsg::Tier {
+                    address: subject.to_string(),
+                },
+            )
+            .unwrap();
+        res
+    };
+
+    // Addresses without a trust account are in the tier of an empty account
+    let res = query_tier(&mut router);
+    assert_eq!(res.tier.unwrap().name, "New");
+    assert_eq!(res.score, Decimal::from_ratio(500u128, 1u128));
+
+    let attester = Addr::unchecked("kyc-provider");
+    router
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            Addr::unchecked(TRUST),
+            &trust::msg::ExecuteMsg::AddAttester {
+                address: attester.to_string(),
+                name: String::from("KYC provider"),
+                score_bonus: 100,
+            },
+            &[],
+        )
+        .unwrap();
+    router
+        .execute_contract(
+            attester,
+            Addr::unchecked(TRUST),
+            &trust::msg::ExecuteMsg::Attest {
+                address: subject.to_string(),
+                kind: String::from("kyc"),
+                uri: None,
+                expires_at: None,
+            },
+            &[],
+        )
+        .unwrap();
+    assert_eq!(query_tier(&mut router).tier.unwrap().name, "Bronze");
+
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(
+            Addr::unchecked(TRUST),
+            &trust::msg::QueryMsg::TrustInfo {
+                address: subject.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.tier.unwrap().name, "Bronze");
+
+    // Tiers must be in ascending order
+    let res: trust::response::ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(Addr::unchecked(TRUST), &trust::msg::QueryMsg::Config {})
+        .unwrap();
+    let config = res.config;
+    let update_tiers_msg = |tiers: Vec<Tier>| trust::msg::ExecuteMsg::UpdateTiers { tiers };
+    let err = router.execute_contract(
+        Addr::unchecked(ADMIN),
+        Addr::unchecked(TRUST),
+        &update_tiers_msg(config.tiers.iter().rev().cloned().collect()),
+        &[],
+    );
+    assert!(err.is_err());
+
+    // Scores below the lowest tier have no tier
+    router
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            Addr::unchecked(TRUST),
+            &update_tiers_msg(vec![Tier {
+                name: String::from("Gold"),
+                min_score: 1000,
+            }]),
+            &[],
+        )
+        .unwrap();
+    assert!(query_tier(&mut router).tier.is_none());
+}
+
+#[test]
+fn try_role_ratings() {
+    let mut router = setup_contract();
+
+    let seller = Addr::unchecked(ADMIN);
+    let buyer = Addr::unchecked(BUYER);
+
+    complete_order(&mut router);
+
+    // Reviews are registered with the role the peer had in the order
+    let res: trust::response::PendingReviewResponse = router
+        .wrap()
+        .query_wasm_smart(
+            Addr::unchecked(TRUST),
+            &trust::msg::QueryMsg::PendingReview {
+                peer: seller.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.pending_review.unwrap().peer_role, TradeRole::Seller);
+
+    commit_seller_review(&mut router);
+    router
+        .execute_contract(
+            buyer.clone(),
+            Addr::unchecked(TRUST),
+            &trust::msg::ExecuteMsg::Review {
+                address: seller.to_string(),
+                review: ReviewResult::ThumbsDown,
+                comment: None,
+            },
+            &[],
+        )
+        .unwrap();
+    router
+        .execute_contract(
+            seller.clone(),
+            Addr::unchecked(TRUST),
+            &trust::msg::ExecuteMsg::RevealReview {
+                address: buyer.to_string(),
+                review: ReviewResult::ThumbsUp,
+                salt: String::from("salt"),
+                comment: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    let query_trust_info = |router: &mut App, address: &Addr| {
+        let res: trust::response::TrustInfoResponse = router
+            .wrap()
+            .query_wasm_smart(
+                Addr::unchecked(TRUST),
+                &trust::msg::QueryMsg::TrustInfo {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap();
+        res
+    };
+
+    // Each role only counts the reviews received in that role
+    let res = query_trust_info(&mut router, &seller);
+    let trust_info = res.trust_info.unwrap();
+    let role_scores = res.role_scores.unwrap();
+    assert_eq!(trust_info.data.seller_rating.rating, -500);
+    assert_eq!(trust_info.data.buyer_rating.rating, 0);
+    assert_eq!(role_scores.seller, trust_info.score);
+    assert_eq!(role_scores.buyer, Decimal::from_ratio(500u128, 1u128));
+    assert!(role_scores.seller < role_scores.buyer);
+
+    let res = query_trust_info(&mut router, &buyer);
+    let trust_info = res.trust_info.unwrap();
+    let role_scores = res.role_scores.unwrap();
+    assert_eq!(trust_info.data.buyer_rating.rating, trust_info.data.rating);
+    assert_eq!(role_scores.buyer, trust_info.score);
+    assert_eq!(role_scores.seller, Decimal::from_ratio(500u128, 1u128));
+
+    let res: trust::response::ReviewsResponse = router
+        .wrap()
+        .query_wasm_smart(
+            Addr::unchecked(TRUST),
+            &trust::msg::QueryMsg::ReviewsByReviewer {
+                reviewer: seller.to_string(),
+                limit: None,
+                start_after: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.reviews[0].peer_role, TradeRole::Buyer);
+
+    // Stores predating roles and order values still register pending reviews,
+    // which only count towards the combined rating, with the minimum weight
+    let legacy_store = Addr::unchecked("legacy-store");
+    router
+        .execute_contract(
+            seller.clone(),
+            Addr::unchecked(TRUST),
+            &trust::msg::ExecuteMsg::AddCommerceContract {
+                address: legacy_store.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    let legacy_register_msg = format!(
+        r#"{{"register_pending_review":{{"peer":"{}","reviewer":"legacy-buyer","order_id":1}}}}"#,
+        seller
+    );
+    router
+        .execute(
+            legacy_store,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: TRUST.to_string(),
+                msg: Binary::from(legacy_register_msg.as_bytes()),
+                funds: vec![],
+            }),
+        )
+        .unwrap();
+    router
+        .execute_contract(
+            Addr::unchecked("legacy-buyer"),
+            Addr::unchecked(TRUST),
+            &trust::msg::ExecuteMsg::Review {
+                address: seller.to_string(),
+                review: ReviewResult::ThumbsUp,
+                comment: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    let res = query_trust_info(&mut router, &seller);
+    let trust_info = res.trust_info.unwrap();
+    assert_eq!(trust_info.data.rating, -400);
+    assert_eq!(trust_info.data.seller_rating.rating, -500);
+    assert_eq!(trust_info.data.buyer_rating.rating, 0);
+}
+
+#[test]
+fn try_store_scoped_trust() {
+    let mut router = setup_contract();
+
+    let seller = Addr::unchecked(ADMIN);
+    let buyer = Addr::unchecked(BUYER);
+    let other_store = Addr::unchecked("other-store");
+
+    complete_order(&mut router);
+    commit_seller_review(&mut router);
+    router
+        .execute_contract(
+            buyer,
+            Addr::unchecked(TRUST),
+            &trust::msg::ExecuteMsg::Review {
+                address: seller.to_string(),
+                review: ReviewResult::ThumbsDown,
+                comment: None,
+            },
+            &[],
+        )
+        .unwrap();
+    reveal_seller_review(&mut router);
+
+    // The seller is also reviewed through another store
+    router
+        .execute_contract(
+            seller.clone(),
+            Addr::unchecked(TRUST),
+            &trust::msg::ExecuteMsg::AddCommerceContract {
+                address: other_store.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    router
+        .execute_contract(
+            other_store.clone(),
+            Addr::unchecked(TRUST),
+            &trust::msg::ExecuteMsg::RegisterPendingReview {
+                peer: seller.to_string(),
+                reviewer: String::from("other-buyer"),
+                order_id: 1,
+                order_value: Some(Uint128::new(1000)),
+                peer_role: Some(TradeRole::Seller),
+            },
+            &[],
+        )
+        .unwrap();
+    router
+        .execute_contract(
+            Addr::unchecked("other-buyer"),
+            Addr::unchecked(TRUST),
+            &trust::msg::ExecuteMsg::Review {
+                address: seller.to_string(),
+                review: ReviewResult::ThumbsUp,
+                comment: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    let query_store_scoped_trust = |router: &mut App, commerce_contract: &str| {
+        let res: trust::response::StoreScopedTrustResponse = router
+            .wrap()
+            .query_wasm_smart(
+                Addr::unchecked(TRUST),
+                &trust::msg::QueryMsg::StoreScopedTrust {
+                    address: seller.to_string(),
+                    commerce_contract: commerce_contract.to_string(),
+                },
+            )
+            .unwrap();
+        res
+    };
+
+    // Each store only sees the reviews left by its own customers
+    let res = query_store_scoped_trust(&mut router, COMMERCE);
+    let store_rating = res.store_rating.unwrap();
+    assert_eq!(store_rating.rating, -500);
+    assert_eq!(store_rating.reviews, 1);
+    let store_score = res.score.unwrap();
+
+    let res = query_store_scoped_trust(&mut router, other_store.as_str());
+    assert_eq!(res.store_rating.unwrap().rating, 500);
+    let other_store_score = res.score.unwrap();
+    assert!(store_score < other_store_score);
+
+    // The global reputation combines both
+    let res: trust::response::TrustInfoResponse = router
+        .wrap()
+        .query_wasm_smart(
+            Addr::unchecked(TRUST),
+            &trust::msg::QueryMsg::TrustInfo {
+                address: seller.to_string(),
+            },
+        )
+        .unwrap();
+    let trust_info = res.trust_info.unwrap();
+    assert_eq!(trust_info.data.rating, 0);
+    assert!(store_score < trust_info.score && trust_info.score < other_store_score);
+
+    let res = query_store_scoped_trust(&mut router, "unknown-store");
+    assert!(res.store_rating.is_none());
+    assert!(res.score.is_none());
+
+    // Reviews are listed by ID, a page at a time
+    let query_reviews = |router: &mut App, start_after: Option<u64>| {
+        let res: trust::response::ReviewsResponse = router
+            .wrap()
+            .query_wasm_smart(
+                Addr::unchecked(TRUST),
+                &trust::msg::QueryMsg::ReviewsByPeer {
+                    peer: seller.to_string(),
+                    limit: Some(1),
+                    start_after,
+                },
+            )
+            .unwrap();
+        res.reviews
+    };
+    let reviews = query_reviews(&mut router, None);
+    assert_eq!(reviews.len(), 1);
+    assert_eq!(reviews[0].commerce_contract, Addr::unchecked(COMMERCE));
+    let reviews = query_reviews(&mut router, Some(reviews[0].id));
+    assert_eq!(reviews.len(), 1);
+    assert_eq!(reviews[0].commerce_contract, other_store);
+    let reviews = query_reviews(&mut router, Some(reviews[0].id));
+    assert!(reviews.is_empty());
+}
@@ -1,6 +1,7 @@
 use cosmwasm_std::{to_binary, Addr, Api, Deps, StdError, StdResult, SubMsg, Uint128, WasmMsg};
 use cw20::{Balance, Cw20Coin, Cw20ExecuteMsg};
 use cw_utils::PaymentError;
+use trust::{msg::QueryMsg as TrustQueryMsg, response::BanResponse};
 
 use crate::{
     state::{
@@ -135,6 +136,30 @@ pub fn eval_cost(deps: Deps, items: Vec<OrderItem>) -> Result<Uint128, ContractE
     Ok(Uint128::from(total_cost))
 }
 
+// Verify with the trust contract that none of the addresses is banned
+pub fn validate_not_banned(
+    deps: Deps,
+    trust_contract: &Addr,
+    addresses: &[Addr],
+) -> Result<(), ContractError> {
+    for address in addresses {
+        let res: BanResponse = deps.querier.query_wasm_smart(
+            trust_contract,
+            &TrustQueryMsg::Ban {
+                address: address.to_string(),
+            },
+        )?;
+
+        if res.active {
+            return Err(ContractError::Banned {
+                address: address.to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
 // Send Cw20 tokens to another address
 pub fn send_cw20_tokens(to: &Addr, balance: &Cw20Coin) -> StdResult<SubMsg> {
     let msg = Cw20ExecuteMsg::Transfer {
@@ -1,15 +1,25 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cosmwasm_std::{
+    to_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response, StdError, StdResult,
+};
 
-use cw2::set_contract_version;
+use cw2::{get_contract_version, set_contract_version};
 
 use crate::execute::*;
 use crate::query::*;
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
-use crate::state::{Config, CONFIG};
+use crate::helpers::{
+    migrate_legacy_config, migrate_legacy_pending_review, migrate_legacy_trust_info,
+    validate_review_weight, validate_score_model, validate_staking_sources, validate_tiers,
+    validate_vouching_params,
+};
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::state::{
+    legacy_pending_reviews, pending_reviews, trust_accounts, Config, COMMERCE_CODE_IDS, CONFIG,
+    LEGACY_CONFIG, LEGACY_TRUST_INFO,
+};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:trust";
@@ -25,36 +35,62 @@ pub fn instantiate(
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     let admin = info.sender;
-    let staking_contract = deps.api.addr_validate(&msg.staking_contract)?;
-    let maintainer = deps.api.addr_validate(&msg.maintainer)?;
+    let staking_sources = validate_staking_sources(deps.api, msg.staking_sources)?;
+    let maintainer = msg
+        .maintainer
+        .map(|maintainer| deps.api.addr_validate(&maintainer))
+        .transpose()?;
 
     let config = Config {
         admin,
         maintainer,
-        staking_contract,
-        commerce_code_id: msg.commerce_code_id,
+        staking_sources,
         review_interval: msg.review_interval,
-        max_staked_tokens: msg.max_staked_tokens,
         max_staked_days: msg.max_staked_days,
+        min_sync_interval: msg.min_sync_interval,
         max_rating: msg.max_rating,
-        trust_score_params: msg.trust_score_params,
+        rating_half_life: msg.rating_half_life,
+        score_model: validate_score_model(msg.score_model)?,
+        review_weight: validate_review_weight(msg.review_weight)?,
+        reviewer_eligibility: msg.reviewer_eligibility,
+        pair_review_limit: msg.pair_review_limit,
+        appeal_window: msg.appeal_window,
+        vouching: validate_vouching_params(deps.api, msg.vouching)?,
+        tiers: validate_tiers(msg.tiers)?,
     };
 
     CONFIG.save(deps.storage, &config)?;
 
+    for code_id in msg.commerce_code_ids.iter() {
+        COMMERCE_CODE_IDS.save(deps.storage, *code_id, &Empty {})?;
+    }
+
     Ok(Response::new()
         .add_attribute("action", "instantiate")
         .add_attribute("contract_addr", env.contract.address.to_string())
         .add_attribute("contract_name", CONTRACT_NAME)
         .add_attribute("contract_version", CONTRACT_VERSION)
         .add_attribute("admin", config.admin)
-        .add_attribute("maintainer", config.maintainer)
-        .add_attribute("staking_contract", config.staking_contract)
-        .add_attribute("commerce_code_id", config.commerce_code_id.to_string())
+        .add_attribute(
+            "maintainer",
+            config
+                .maintainer
+                .map_or(String::from("none"), |maintainer| maintainer.to_string()),
+        )
+        .add_attribute("staking_sources", config.staking_sources.len().to_string())
+        .add_attribute(
+            "commerce_code_ids",
+            msg.commerce_code_ids
+                .iter()
+                .map(|code_id| code_id.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        )
         .add_attribute("review_interval", config.review_interval.to_string())
-        .add_attribute("max_staked_tokens", config.max_staked_tokens.to_string())
         .add_attribute("max_staked_days", config.max_staked_days.to_string())
-        .add_attribute("max_rating", config.max_rating.to_string()))
+        .add_attribute("min_sync_interval", config.min_sync_interval.to_string())
+        .add_attribute("max_rating", config.max_rating.to_string())
+        .add_attribute("rating_half_life", config.rating_half_life.to_string()))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -66,50 +102,274 @@ pub fn execute(
 ) -> Result<Response, ContractError> {
     match msg {
         ExecuteMsg::UpdateStakingInfo { address } => {
-            execute_update_staking_info(deps, info, address)
+            execute_update_staking_info(deps, info, env, address)
+        }
+        ExecuteMsg::UpdateStakingInfoBatch { addresses } => {
+            execute_update_staking_info_batch(deps, info, env, addresses)
         }
+        ExecuteMsg::UpdateStakingInfoNext { limit } => {
+            execute_update_staking_info_next(deps, info, env, limit)
+        }
+        ExecuteMsg::Register {} => execute_register(deps, info, env),
+        ExecuteMsg::Unvouch { address, amount } => {
+            execute_unvouch(deps, info, env, address, amount)
+        }
+        ExecuteMsg::ClaimUnvouched {} => execute_claim_unvouched(deps, info, env),
+        ExecuteMsg::Receive(msg) => execute_receive(deps, info, env, msg),
+        ExecuteMsg::StakeChangeHook(msg) => execute_stake_change_hook(deps, info, env, msg),
         ExecuteMsg::UpdateConfig {
             admin,
             maintainer,
-            staking_contract,
-            commerce_code_id,
+            staking_sources,
             review_interval,
-            max_staked_tokens,
             max_staked_days,
+            min_sync_interval,
             max_rating,
-            trust_score_params,
+            rating_half_life,
+            score_model,
         } => execute_update_config(
             deps,
             info,
             admin,
             maintainer,
-            staking_contract,
-            commerce_code_id,
+            staking_sources,
             review_interval,
-            max_staked_tokens,
             max_staked_days,
+            min_sync_interval,
             max_rating,
-            trust_score_params,
+            rating_half_life,
+            score_model,
+        ),
+        ExecuteMsg::UpdateReviewParams {
+            review_weight,
+            reviewer_eligibility,
+            pair_review_limit,
+            appeal_window,
+        } => execute_update_review_params(
+            deps,
+            info,
+            review_weight,
+            reviewer_eligibility,
+            pair_review_limit,
+            appeal_window,
         ),
+        ExecuteMsg::UpdateVouchingParams { vouching } => {
+            execute_update_vouching_params(deps, info, vouching)
+        }
+        ExecuteMsg::UpdateTiers { tiers } => execute_update_tiers(deps, info, tiers),
+        ExecuteMsg::AddCommerceCodeId { code_id } => {
+            execute_add_commerce_code_id(deps, info, code_id)
+        }
+        ExecuteMsg::RemoveCommerceCodeId { code_id } => {
+            execute_remove_commerce_code_id(deps, info, code_id)
+        }
+        ExecuteMsg::AddCommerceContract { address } => {
+            execute_add_commerce_contract(deps, info, address)
+        }
+        ExecuteMsg::RemoveCommerceContract { address } => {
+            execute_remove_commerce_contract(deps, info, address)
+        }
         ExecuteMsg::RegisterPendingReview {
             peer,
             reviewer,
             order_id,
-        } => execute_register_pending_review(deps, info, env, peer, reviewer, order_id),
-        ExecuteMsg::Review { address, review } => execute_review(deps, info, env, address, review),
+            order_value,
+            peer_role,
+        } => execute_register_pending_review(
+            deps,
+            info,
+            env,
+            peer,
+            reviewer,
+            order_id,
+            order_value,
+            peer_role,
+        ),
+        ExecuteMsg::Review {
+            address,
+            review,
+            comment,
+        } => execute_review(deps, info, env, address, review, comment),
+        ExecuteMsg::CommitReview {
+            address,
+            commitment,
+        } => execute_commit_review(deps, info, env, address, commitment),
+        ExecuteMsg::RevealReview {
+            address,
+            review,
+            salt,
+            comment,
+        } => execute_reveal_review(deps, info, env, address, review, salt, comment),
+        ExecuteMsg::SettleReview { address } => execute_settle_review(deps, env, address),
+        ExecuteMsg::AppealReview { id, reason } => {
+            execute_appeal_review(deps, info, env, id, reason)
+        }
+        ExecuteMsg::AddAttester {
+            address,
+            name,
+            score_bonus,
+        } => execute_add_attester(deps, info, address, name, score_bonus),
+        ExecuteMsg::RemoveAttester { address } => execute_remove_attester(deps, info, address),
+        ExecuteMsg::Attest {
+            address,
+            kind,
+            uri,
+            expires_at,
+        } => execute_attest(deps, info, env, address, kind, uri, expires_at),
+        ExecuteMsg::RevokeAttestation {
+            address,
+            kind,
+            attester,
+        } => execute_revoke_attestation(deps, info, env, address, kind, attester),
+        ExecuteMsg::DecideAppeal { id, decision } => {
+            execute_decide_appeal(deps, info, env, id, decision)
+        }
+        ExecuteMsg::Ban {
+            address,
+            reason,
+            expires_at,
+        } => execute_ban(deps, info, env, address, reason, expires_at),
+        ExecuteMsg::Unban { address } => execute_unban(deps, info, env, address),
+        ExecuteMsg::Slash {
+            address,
+            start_after,
+            limit,
+        } => execute_slash(deps, info, env, address, start_after, limit),
+        ExecuteMsg::ReplyToReview { id, reply } => execute_reply_to_review(deps, info, id, reply),
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_binary(&query_config(deps)?),
+        QueryMsg::CommerceCodeIds {} => to_binary(&query_commerce_code_ids(deps)?),
+        QueryMsg::CommerceContracts {} => to_binary(&query_commerce_contracts(deps)?),
+        QueryMsg::Attesters {} => to_binary(&query_attesters(deps)?),
+        QueryMsg::Ban { address } => to_binary(&query_ban(deps, env, address)?),
+        QueryMsg::Bans { limit, start_after } => to_binary(&query_bans(deps, limit, start_after)?),
+        QueryMsg::ScoreBreakdown { address } => {
+            to_binary(&query_score_breakdown(deps, env, address)?)
+        }
+        QueryMsg::SimulateScore { data } => to_binary(&query_simulate_score(deps, *data)?),
+        QueryMsg::TrustInfoAtHeight { address, height } => {
+            to_binary(&query_trust_info_at_height(deps, address, height)?)
+        }
         QueryMsg::StakeAmount { address } => to_binary(&query_stake_amount(deps, address)?),
-        QueryMsg::TrustInfo { address } => to_binary(&query_trust_info(deps, address)?),
+        QueryMsg::TrustInfo { address } => to_binary(&query_trust_info(deps, env, address)?),
+        QueryMsg::Tier { address } => to_binary(&query_tier(deps, env, address)?),
+        QueryMsg::StoreScopedTrust {
+            address,
+            commerce_contract,
+        } => to_binary(&query_store_scoped_trust(
+            deps,
+            env,
+            address,
+            commerce_contract,
+        )?),
         QueryMsg::Accounts {} => to_binary(&query_accounts(deps)?),
+        QueryMsg::TopAccounts { limit, start_after } => {
+            to_binary(&query_top_accounts(deps, limit, start_after)?)
+        }
+        QueryMsg::Rank { address } => to_binary(&query_rank(deps, address)?),
         QueryMsg::PendingReview { peer } => to_binary(&query_pending_review(deps, peer)?),
         QueryMsg::PendingReviewsByReviewer { reviewer } => {
             to_binary(&query_pending_reviews_by_reviewer(deps, reviewer)?)
         }
+        QueryMsg::Review { id } => to_binary(&query_review(deps, id)?),
+        QueryMsg::ReviewsByPeer {
+            peer,
+            limit,
+            start_after,
+        } => to_binary(&query_reviews_by_peer(deps, peer, limit, start_after)?),
+        QueryMsg::ReviewsByReviewer {
+            reviewer,
+            limit,
+            start_after,
+        } => to_binary(&query_reviews_by_reviewer(
+            deps,
+            reviewer,
+            limit,
+            start_after,
+        )?),
+        QueryMsg::VouchesGiven { voucher } => to_binary(&query_vouches_given(deps, voucher)?),
+        QueryMsg::VouchesReceived { vouchee } => to_binary(&query_vouches_received(deps, vouchee)?),
+        QueryMsg::Unbondings { voucher } => to_binary(&query_unbondings(deps, voucher)?),
+        QueryMsg::Appeal { id } => to_binary(&query_appeal(deps, id)?),
+        QueryMsg::AppealsByPeer { peer } => to_binary(&query_appeals_by_peer(deps, peer)?),
+        QueryMsg::Appeals {
+            status,
+            limit,
+            start_after,
+        } => to_binary(&query_appeals(deps, status, limit, start_after)?),
+        QueryMsg::PairReviewCounter { peer, reviewer } => {
+            to_binary(&query_pair_review_counter(deps, peer, reviewer)?)
+        }
+        QueryMsg::PairReviewCounters {
+            peer,
+            limit,
+            start_after,
+        } => to_binary(&query_pair_review_counters(deps, peer, limit, start_after)?),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    let version = get_contract_version(deps.storage)?;
+    if version.contract != CONTRACT_NAME {
+        return Err(ContractError::CannotMigrate {
+            previous_contract: version.contract,
+        });
     }
+
+    // Only the first release stored the config and trust accounts in their legacy shape,
+    // which doesn't deserialize into the current ones
+    let mut accounts = vec![];
+    let mut pending = vec![];
+    if let Ok(legacy_config) = LEGACY_CONFIG.load(deps.storage) {
+        // The single commerce code ID becomes the first approved one
+        COMMERCE_CODE_IDS.save(deps.storage, legacy_config.commerce_code_id, &Empty {})?;
+
+        let vouching = msg.vouching.ok_or_else(|| {
+            StdError::generic_err("Vouching params are required to migrate from the first release")
+        })?;
+        let config =
+            migrate_legacy_config(legacy_config, validate_vouching_params(deps.api, vouching)?);
+        CONFIG.save(deps.storage, &config)?;
+
+        // Trust accounts used to be a plain map under the same namespace
+        // They are removed before being saved again, as saving reads the previous value
+        // to update the score index and start their history
+        accounts = LEGACY_TRUST_INFO
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for (address, legacy_trust_info) in accounts.iter() {
+            let trust_info =
+                migrate_legacy_trust_info(legacy_trust_info.clone(), &config, env.block.time);
+            LEGACY_TRUST_INFO.remove(deps.storage, address);
+            trust_accounts().save(deps.storage, address, &trust_info, env.block.height)?;
+        }
+
+        // Pending reviews are rewritten the same way, with an unknown order value and role
+        pending = legacy_pending_reviews()
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for (peer, legacy_pending_review) in pending.iter() {
+            legacy_pending_reviews().remove(deps.storage, peer.clone())?;
+            pending_reviews().save(
+                deps.storage,
+                peer.clone(),
+                &migrate_legacy_pending_review(legacy_pending_review.clone()),
+            )?;
+        }
+    }
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("previous_version", version.version)
+        .add_attribute("contract_version", CONTRACT_VERSION)
+        .add_attribute("accounts", accounts.len().to_string())
+        .add_attribute("pending_reviews", pending.len().to_string()))
 }
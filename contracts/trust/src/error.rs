@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
 use cw_utils::PaymentError;
 use thiserror::Error;
 
@@ -10,12 +10,114 @@ pub enum ContractError {
     #[error("Unauthorized")]
     Unauthorized {},
 
-    #[error("InvalidContract, expected codeID: {expected_code_id}")]
-    InvalidContract { expected_code_id: String },
+    #[error("UnapprovedContract, not an approved commerce contract: {contract}")]
+    UnapprovedContract { contract: String },
 
     #[error("AwaitingReview, from reviewer: {reviewer}")]
     AwaitingReview { reviewer: String },
 
+    #[error("CommentTooLong, max length: {max_length}")]
+    CommentTooLong { max_length: u64 },
+
+    #[error("EmptyComment, leave the comment out instead")]
+    EmptyComment {},
+
+    #[error("InvalidContentHash, expected a hex-encoded SHA-256 hash")]
+    InvalidContentHash {},
+
+    #[error("InvalidUri, max length: {max_length}")]
+    InvalidUri { max_length: u64 },
+
+    #[error("AlreadyReplied, to review: {review_id}")]
+    AlreadyReplied { review_id: u64 },
+
+    #[error("SyncTooSoon, next sync allowed at: {next_sync}")]
+    SyncTooSoon { next_sync: String },
+
+    #[error("InvalidLimit, must be greater than zero")]
+    InvalidLimit {},
+
+    #[error("CannotMigrate, from contract: {previous_contract}")]
+    CannotMigrate { previous_contract: String },
+
+    #[error("IneligibleReviewer, requirement not met: {requirement}")]
+    IneligibleReviewer { requirement: String },
+
+    #[error("IneligibleVoucher, requirement not met: {requirement}")]
+    IneligibleVoucher { requirement: String },
+
+    #[error("AlreadyCommitted, the review can only be revealed")]
+    AlreadyCommitted {},
+
+    #[error("NotCommitted, commit to the review before revealing it")]
+    NotCommitted {},
+
+    #[error("AwaitingCommitment, from counterpart: {counterpart}")]
+    AwaitingCommitment { counterpart: String },
+
+    #[error("InvalidCommitment, expected a hex-encoded SHA-256 hash matching the review")]
+    InvalidCommitment {},
+
+    #[error("AlreadyReviewed, the review is held back until the counterpart reveals theirs")]
+    AlreadyReviewed {},
+
+    #[error("AwaitingReveal, from counterpart: {counterpart}")]
+    AwaitingReveal { counterpart: String },
+
+    #[error("NoHeldReview, for peer: {peer}")]
+    NoHeldReview { peer: String },
+
+    #[error("ReviewWindowEnded")]
+    ReviewWindowEnded {},
+
+    #[error("NotAppealable, only negative reviews can be appealed")]
+    NotAppealable {},
+
+    #[error("AppealWindowEnded, for review: {review_id}")]
+    AppealWindowEnded { review_id: u64 },
+
+    #[error("AlreadyAppealed, review: {review_id}")]
+    AlreadyAppealed { review_id: u64 },
+
+    #[error("AppealAlreadyDecided, for review: {review_id}")]
+    AppealAlreadyDecided { review_id: u64 },
+
+    #[error("InvalidAttestationKind, max length: {max_length}")]
+    InvalidAttestationKind { max_length: u64 },
+
+    #[error("InvalidExpiration, must be in the future")]
+    InvalidExpiration {},
+
+    #[error("InvalidToken, not the vouching token: {token}")]
+    InvalidToken { token: String },
+
+    #[error("CannotVouchForSelf")]
+    CannotVouchForSelf {},
+
+    #[error("InvalidUnvouchAmount, amount vouched: {vouched}")]
+    InvalidUnvouchAmount { vouched: Uint128 },
+
+    #[error("NothingToClaim")]
+    NothingToClaim {},
+
+    #[error("NothingToSlash")]
+    NothingToSlash {},
+
+    #[error("VouchingTokenLocked, tokens are still vouched or unbonding")]
+    VouchingTokenLocked {},
+
+    #[error("Banned, address: {address}")]
+    Banned { address: String },
+
+    #[error("NotBanned, address: {address}")]
+    NotBanned { address: String },
+
+    #[error("InvalidReason, must not be empty")]
+    InvalidReason {},
+
+    #[error("AlreadyRegistered")]
+    AlreadyRegistered {},
+
     #[error("{0}")]
     PaymentError(#[from] PaymentError),
 
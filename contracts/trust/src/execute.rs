@@ -1,27 +1,53 @@
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdError, Uint128};
+use cosmwasm_std::{
+    from_binary, Addr, Decimal, Deps, DepsMut, Empty, Env, Event, MessageInfo, Order, Response,
+    StdError, StdResult, Storage, Timestamp, Uint128,
+};
+use cw20::{Cw20Coin, Cw20ReceiveMsg};
+use cw20_stake::hooks::StakeChangedHookMsg;
+use cw_storage_plus::Bound;
 
 use crate::{
-    helpers::calculate_trust_score,
-    query::query_stake_amount,
+    helpers::{
+        add_rating_delta, add_store_rating_delta, apply_pair_review_limit, calculate_rating_delta,
+        calculate_review_weight, calculate_trust_score, current_trust_info, ensure_not_banned,
+        refresh_trust_data, restore_rating_delta, restore_store_rating_delta, reverse_rating_delta,
+        reverse_store_rating_delta, review_commitment, send_cw20_tokens,
+        unmet_reviewer_requirement, unmet_voucher_requirement, update_stake, validate_attestation,
+        validate_ban, validate_review_comment, validate_review_commitment, validate_review_weight,
+        validate_score_model, validate_staking_sources, validate_tiers, validate_vouching_params,
+        weighted_stake_amount, weighted_vouch_amount,
+    },
+    msg::{ReceiveMsg, StakingSourceMsg, VouchingParamsMsg},
+    query::query_source_stake,
     state::{
-        pending_reviews, Config, PendingReview, ReviewResult, TrustData, TrustInfo,
-        TrustScoreParams, CONFIG, TRUST_INFO,
+        appeals, next_review_id, pending_reviews, reviews, trust_accounts, unbondings, vouches,
+        Appeal, AppealDecision, AppealStatus, Attestation, Attester, Ban, Config, HeldReview,
+        IneligibleReviewPolicy, PairReviewLimit, PendingReview, Review, ReviewComment,
+        ReviewResult, ReviewWeightParams, ReviewerEligibility, ScoreModel, SourceStake,
+        StakeSource, StoreRating, Tier, TradeRole, TrustData, TrustInfo, Unbonding, Vouch,
+        ATTESTERS, BANS, COMMERCE_CODE_IDS, COMMERCE_CONTRACTS, CONFIG, HELD_REVIEWS, PAIR_REVIEWS,
+        STORE_RATINGS, SYNC_CURSORS,
     },
     ContractError,
 };
 
+/// Maximum amount of accounts synced by a single `UpdateStakingInfoNext`
+const MAX_SYNC_LIMIT: u32 = 50;
+const DEFAULT_SLASH_LIMIT: u32 = 10;
+const MAX_SLASH_LIMIT: u32 = 30;
+
 pub fn execute_update_config(
     deps: DepsMut,
     info: MessageInfo,
     admin: String,
-    maintainer: String,
-    staking_contract: String,
-    commerce_code_id: u64,
+    maintainer: Option<String>,
+    staking_sources: Vec<StakingSourceMsg>,
     review_interval: u64,
-    max_staked_tokens: Uint128,
     max_staked_days: u64,
+    min_sync_interval: u64,
     max_rating: u64,
-    trust_score_params: TrustScoreParams,
+    rating_half_life: u64,
+    score_model: ScoreModel,
 ) -> Result<Response, ContractError> {
     let api = deps.api;
     let config = CONFIG.load(deps.storage)?;
@@ -31,19 +57,23 @@ pub fn execute_update_config(
     }
 
     let admin = api.addr_validate(&admin)?;
-    let maintainer = api.addr_validate(&maintainer)?;
-    let staking_contract = api.addr_validate(&staking_contract)?;
+    let maintainer = maintainer
+        .map(|maintainer| api.addr_validate(&maintainer))
+        .transpose()?;
+    let staking_sources = validate_staking_sources(api, staking_sources)?;
+    let score_model = validate_score_model(score_model)?;
 
     let config = Config {
         admin,
         maintainer,
-        staking_contract,
-        commerce_code_id,
+        staking_sources,
         review_interval,
-        max_staked_tokens,
         max_staked_days,
+        min_sync_interval,
         max_rating,
-        trust_score_params,
+        rating_half_life,
+        score_model,
+        ..config
     };
 
     CONFIG.save(deps.storage, &config)?;
@@ -52,18 +82,201 @@ pub fn execute_update_config(
         .add_attribute("action", "update_config")
         .add_attribute("sender", info.sender)
         .add_attribute("admin", config.admin)
-        .add_attribute("maintainer", config.maintainer)
-        .add_attribute("staking_contract", config.staking_contract)
-        .add_attribute("commerce_code_id", config.commerce_code_id.to_string())
+        .add_attribute(
+            "maintainer",
+            config
+                .maintainer
+                .map_or(String::from("none"), |maintainer| maintainer.to_string()),
+        )
+        .add_attribute("staking_sources", config.staking_sources.len().to_string())
         .add_attribute("review_interval", config.review_interval.to_string())
-        .add_attribute("max_staked_tokens", config.max_staked_tokens.to_string())
         .add_attribute("max_staked_days", config.max_staked_days.to_string())
-        .add_attribute("max_rating", config.max_rating.to_string()))
+        .add_attribute("min_sync_interval", config.min_sync_interval.to_string())
+        .add_attribute("max_rating", config.max_rating.to_string())
+        .add_attribute("rating_half_life", config.rating_half_life.to_string()))
+}
+
+pub fn execute_update_review_params(
+    deps: DepsMut,
+    info: MessageInfo,
+    review_weight: ReviewWeightParams,
+    reviewer_eligibility: ReviewerEligibility,
+    pair_review_limit: PairReviewLimit,
+    appeal_window: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let review_weight = validate_review_weight(review_weight)?;
+
+    let config = Config {
+        review_weight,
+        reviewer_eligibility,
+        pair_review_limit,
+        appeal_window,
+        ..config
+    };
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_review_params")
+        .add_attribute("sender", info.sender)
+        .add_attribute("appeal_window", config.appeal_window.to_string()))
+}
+
+pub fn execute_update_vouching_params(
+    deps: DepsMut,
+    info: MessageInfo,
+    vouching: VouchingParamsMsg,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let vouching = validate_vouching_params(deps.api, vouching)?;
+
+    // Vouched and unbonding tokens are paid out in the configured token, so it can't change under them
+    if vouching.token != config.vouching.token
+        && (!vouches().is_empty(deps.storage) || !unbondings().is_empty(deps.storage))
+    {
+        return Err(ContractError::VouchingTokenLocked {});
+    }
+
+    let config = Config { vouching, ..config };
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_vouching_params")
+        .add_attribute("sender", info.sender)
+        .add_attribute("token", config.vouching.token))
+}
+
+pub fn execute_update_tiers(
+    deps: DepsMut,
+    info: MessageInfo,
+    tiers: Vec<Tier>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let tiers = validate_tiers(tiers)?;
+
+    let config = Config { tiers, ..config };
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_tiers")
+        .add_attribute("sender", info.sender)
+        .add_attribute("tiers", config.tiers.len().to_string()))
+}
+
+pub fn execute_add_commerce_code_id(
+    deps: DepsMut,
+    info: MessageInfo,
+    code_id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    COMMERCE_CODE_IDS.save(deps.storage, code_id, &Empty {})?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_commerce_code_id")
+        .add_attribute("sender", info.sender)
+        .add_attribute("code_id", code_id.to_string()))
+}
+
+pub fn execute_remove_commerce_code_id(
+    deps: DepsMut,
+    info: MessageInfo,
+    code_id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if !COMMERCE_CODE_IDS.has(deps.storage, code_id) {
+        return Err(ContractError::Std(StdError::NotFound {
+            kind: String::from("commerce_code_id"),
+        }));
+    }
+
+    COMMERCE_CODE_IDS.remove(deps.storage, code_id);
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_commerce_code_id")
+        .add_attribute("sender", info.sender)
+        .add_attribute("code_id", code_id.to_string()))
+}
+
+pub fn execute_add_commerce_contract(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let contract = deps.api.addr_validate(&address)?;
+
+    COMMERCE_CONTRACTS.save(deps.storage, &contract, &Empty {})?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_commerce_contract")
+        .add_attribute("sender", info.sender)
+        .add_attribute("contract", contract))
+}
+
+pub fn execute_remove_commerce_contract(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let contract = deps.api.addr_validate(&address)?;
+
+    if !COMMERCE_CONTRACTS.has(deps.storage, &contract) {
+        return Err(ContractError::Std(StdError::NotFound {
+            kind: String::from("commerce_contract"),
+        }));
+    }
+
+    COMMERCE_CONTRACTS.remove(deps.storage, &contract);
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_commerce_contract")
+        .add_attribute("sender", info.sender)
+        .add_attribute("contract", contract))
 }
 
 pub fn execute_update_staking_info(
     deps: DepsMut,
     info: MessageInfo,
+    env: Env,
     address: String,
 ) -> Result<Response, ContractError> {
     let api = deps.api;
@@ -71,65 +284,336 @@ pub fn execute_update_staking_info(
 
     let config = CONFIG.load(deps.storage)?;
 
-    // Only the maintainer can execute this message
-    if info.sender != config.maintainer {
-        return Err(ContractError::Unauthorized {});
+    let trust_info = sync_staking_info(deps, &env, &config, &info.sender, &staker)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_staking_info")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("delegator", staker.to_string())
+        .add_attribute("stake_days", trust_info.data.stake_days.to_string())
+        .add_attribute(
+            "stake_amount",
+            weighted_stake_amount(&trust_info.data, &config.staking_sources).to_string(),
+        )
+        .add_attribute(
+            "stake_start",
+            trust_info
+                .data
+                .stake_start
+                .map_or(String::from("none"), |start| start.to_string()),
+        ))
+}
+
+pub fn execute_update_staking_info_batch(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    addresses: Vec<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let mut events = vec![];
+    for address in addresses {
+        events.push(sync_staking_event(
+            deps.branch(),
+            &env,
+            &config,
+            &info.sender,
+            address,
+        ));
     }
 
-    let res = query_stake_amount(deps.as_ref(), staker.to_string())?;
+    Ok(Response::new()
+        .add_attribute("action", "update_staking_info_batch")
+        .add_attribute("sender", info.sender.to_string())
+        .add_events(events))
+}
+
+pub fn execute_update_staking_info_next(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    limit: u32,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
 
-    match res.stake_amount {
-        Some(stake_amount) => {
-            if stake_amount.u128() == 0 {
-                return Err(ContractError::Std(StdError::NotFound {
-                    kind: String::from("staking_account"),
-                }));
-            };
+    if limit == 0 {
+        return Err(ContractError::InvalidLimit {});
+    }
+    let limit = limit.min(MAX_SYNC_LIMIT) as usize;
 
-            TRUST_INFO.update(deps.storage, &staker, |info| match info {
-                Some(info) => {
-                    let stake_days;
+    // Walk the trust accounts in order, starting after the last one synced by the sender
+    let cursor = SYNC_CURSORS.may_load(deps.storage, &info.sender)?;
+    let stakers = trust_accounts()
+        .keys(
+            deps.storage,
+            cursor.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
 
-                    if stake_amount < info.data.prev_stake_amount {
-                        stake_days = 0;
-                    } else if stake_amount >= info.data.prev_stake_amount {
-                        stake_days = info.data.stake_days + 1
-                    } else {
-                        stake_days = 0;
-                    }
+    // Once the end is reached, start over from the first account
+    match stakers.last() {
+        Some(last) if stakers.len() == limit => {
+            SYNC_CURSORS.save(deps.storage, &info.sender, last)?
+        }
+        _ => SYNC_CURSORS.remove(deps.storage, &info.sender),
+    }
 
-                    let data = TrustData {
-                        stake_days,
-                        stake_amount,
-                        prev_stake_amount: info.data.stake_amount,
-                        rating: info.data.rating,
-                    };
+    let mut events = vec![];
+    for staker in stakers {
+        events.push(sync_staking_event(
+            deps.branch(),
+            &env,
+            &config,
+            &info.sender,
+            staker.to_string(),
+        ));
+    }
 
-                    let score = calculate_trust_score(data.clone(), config.clone());
+    Ok(Response::new()
+        .add_attribute("action", "update_staking_info_next")
+        .add_attribute("sender", info.sender.to_string())
+        .add_events(events))
+}
 
-                    Ok(TrustInfo { score, data })
-                }
-                None => Err(StdError::NotFound {
-                    kind: String::from("trust_account"),
-                }),
-            })?;
+// Re-query the stake of an address and update their trust info
+fn sync_staking_info(
+    deps: DepsMut,
+    env: &Env,
+    config: &Config,
+    sender: &Addr,
+    staker: &Addr,
+) -> Result<TrustInfo, ContractError> {
+    // Anyone can sync an account, but only once every `min_sync_interval`
+    // The maintainer is not rate-limited
+    if config.maintainer.as_ref() != Some(sender) {
+        let last_synced = trust_accounts()
+            .may_load(deps.storage, staker)?
+            .and_then(|info| info.data.last_synced);
 
-            let trust_info = TRUST_INFO.load(deps.storage, &info.sender)?;
-
-            Ok(Response::new()
-                .add_attribute("action", "update_staking_info")
-                .add_attribute("delegator", info.sender.to_string())
-                .add_attribute("stake_days", trust_info.data.stake_days.to_string())
-                .add_attribute("stake_amount", trust_info.data.stake_amount.to_string())
-                .add_attribute(
-                    "prev_stake_amount",
-                    trust_info.data.prev_stake_amount.to_string(),
-                ))
+        if let Some(last_synced) = last_synced {
+            let next_sync = last_synced.plus_seconds(config.min_sync_interval);
+            if env.block.time < next_sync {
+                return Err(ContractError::SyncTooSoon {
+                    next_sync: next_sync.to_string(),
+                });
+            }
         }
-        None => Err(ContractError::Std(StdError::NotFound {
+    }
+
+    let observed = query_stakes(deps.as_ref(), config, staker);
+
+    // None of the staking sources could be queried
+    if observed.is_empty() {
+        return Err(ContractError::Std(StdError::NotFound {
             kind: String::from("staking_account"),
-        })),
+        }));
+    }
+
+    // Nothing to track if no tokens were ever observed as staked
+    let observed_stakes = trust_accounts()
+        .may_load(deps.storage, staker)?
+        .map(|info| info.data.stakes)
+        .unwrap_or_default();
+    if observed.iter().all(|stake| stake.amount.is_zero()) && observed_stakes.is_empty() {
+        return Err(ContractError::Std(StdError::NotFound {
+            kind: String::from("staking_account"),
+        }));
+    }
+
+    Ok(save_stakes(deps.storage, env, config, staker, observed)?)
+}
+
+// Query the stake of an address in every staking source that can be queried
+fn query_stakes(deps: Deps, config: &Config, staker: &Addr) -> Vec<SourceStake> {
+    config
+        .staking_sources
+        .iter()
+        .filter_map(|source| {
+            query_source_stake(deps, source, staker).map(|amount| SourceStake {
+                source: source.source.clone(),
+                amount,
+            })
+        })
+        .collect()
+}
+
+// Sync an address, reporting the outcome in an event instead of failing
+fn sync_staking_event(
+    deps: DepsMut,
+    env: &Env,
+    config: &Config,
+    sender: &Addr,
+    address: String,
+) -> Event {
+    let event = Event::new("update_staking_info").add_attribute("delegator", &address);
+
+    let res = match deps.api.addr_validate(&address) {
+        Ok(staker) => sync_staking_info(deps, env, config, sender, &staker),
+        Err(err) => Err(ContractError::Std(err)),
+    };
+
+    match res {
+        Ok(trust_info) => event
+            .add_attribute("status", "ok")
+            .add_attribute("stake_days", trust_info.data.stake_days.to_string())
+            .add_attribute(
+                "stake_amount",
+                weighted_stake_amount(&trust_info.data, &config.staking_sources).to_string(),
+            ),
+        Err(err) => event
+            .add_attribute("status", "error")
+            .add_attribute("error", err.to_string()),
+    }
+}
+
+pub fn execute_stake_change_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    msg: StakeChangedHookMsg,
+) -> Result<Response, ContractError> {
+    // Staking contracts send hooks as plain submessages, so an error here would revert
+    // the stake or unstake itself. The hook is best-effort and reports failures instead,
+    // accounts can still be synced with `UpdateStakingInfo`.
+    match apply_stake_change(deps, info, env, msg) {
+        Ok(response) => Ok(response),
+        Err(err) => Ok(Response::new()
+            .add_attribute("action", "stake_change_hook")
+            .add_attribute("skipped", "error")
+            .add_attribute("error", err.to_string())),
+    }
+}
+
+fn apply_stake_change(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    msg: StakeChangedHookMsg,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only staking sources can notify us of stake changes
+    // Hooks from contracts removed from the sources are ignored
+    let source = match config.staking_sources.iter().find(|source| {
+        source.source
+            == StakeSource::Cw20Stake {
+                contract: info.sender.clone(),
+            }
+    }) {
+        Some(source) => source,
+        None => {
+            return Ok(Response::new()
+                .add_attribute("action", "stake_change_hook")
+                .add_attribute("skipped", "unknown_source")
+                .add_attribute("sender", info.sender.to_string()))
+        }
+    };
+
+    let (hook, staker) = match &msg {
+        StakeChangedHookMsg::Stake { addr, .. } => ("stake", addr.clone()),
+        StakeChangedHookMsg::Unstake { addr, .. } => ("unstake", addr.clone()),
+    };
+
+    let response = Response::new()
+        .add_attribute("action", "stake_change_hook")
+        .add_attribute("hook", hook)
+        .add_attribute("source", source.source.to_string())
+        .add_attribute("staker", staker.to_string());
+
+    let observed_stakes = trust_accounts()
+        .may_load(deps.storage, &staker)?
+        .map(|info| info.data.stakes)
+        .unwrap_or_default();
+    let observed_stake = observed_stakes
+        .iter()
+        .find(|stake| stake.source == source.source)
+        .map_or(Uint128::zero(), |stake| stake.amount);
+
+    // The staked value is re-queried so it matches what `UpdateStakingInfo` would observe
+    // If the query fails, the hook's amount is applied to the last observed stake instead
+    let stake_amount = match query_source_stake(deps.as_ref(), source, &staker) {
+        Some(stake_amount) => stake_amount,
+        None => match msg {
+            StakeChangedHookMsg::Stake { amount, .. } => observed_stake + amount,
+            StakeChangedHookMsg::Unstake { amount, .. } => observed_stake.saturating_sub(amount),
+        },
+    };
+
+    // Nothing to track if no tokens were ever observed as staked
+    if stake_amount.is_zero() && observed_stakes.is_empty() {
+        return Ok(response.add_attribute("skipped", "no_stake"));
+    }
+
+    let observed = vec![SourceStake {
+        source: source.source.clone(),
+        amount: stake_amount,
+    }];
+    let trust_info = save_stakes(deps.storage, &env, &config, &staker, observed)?;
+
+    Ok(response
+        .add_attribute("stake_days", trust_info.data.stake_days.to_string())
+        .add_attribute("stake_amount", stake_amount.to_string())
+        .add_attribute("new_score", trust_info.score.to_string()))
+}
+
+// Record newly observed stake amounts and recompute the trust score
+// Stakers without a trust account get one
+fn save_stakes(
+    storage: &mut dyn Storage,
+    env: &Env,
+    config: &Config,
+    staker: &Addr,
+    observed: Vec<SourceStake>,
+) -> StdResult<TrustInfo> {
+    let data = trust_accounts()
+        .may_load(storage, staker)?
+        .map_or_else(|| TrustData::new(env.block.time), |info| info.data);
+
+    let data = update_stake(data, observed, env.block.time);
+    let data = TrustData {
+        last_synced: Some(env.block.time),
+        ..data
+    };
+    let data = refresh_trust_data(storage, data, config, env.block.time)?;
+
+    let score = calculate_trust_score(data.clone(), config.clone());
+
+    let trust_info = TrustInfo { score, data };
+    trust_accounts().save(storage, staker, &trust_info, env.block.height)?;
+
+    Ok(trust_info)
+}
+
+pub fn execute_register(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if trust_accounts()
+        .may_load(deps.storage, &info.sender)?
+        .is_some()
+    {
+        return Err(ContractError::AlreadyRegistered {});
     }
+
+    // Start the account off with whatever is currently staked
+    let observed = query_stakes(deps.as_ref(), &config, &info.sender);
+    let trust_info = save_stakes(deps.storage, &env, &config, &info.sender, observed)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "register")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute(
+            "stake_amount",
+            weighted_stake_amount(&trust_info.data, &config.staking_sources).to_string(),
+        )
+        .add_attribute("new_score", trust_info.score.to_string()))
 }
 
 pub fn execute_register_pending_review(
@@ -139,32 +623,50 @@ pub fn execute_register_pending_review(
     peer: String,
     reviewer: String,
     order_id: u64,
+    order_value: Option<Uint128>,
+    peer_role: Option<TradeRole>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
+    let peer_role = peer_role.unwrap_or(TradeRole::Unknown);
 
     let api = deps.api;
     let peer = api.addr_validate(&peer)?;
     let reviewer = api.addr_validate(&reviewer)?;
 
-    // Verify that the sender is a commerce contract
-    let contract_info = deps.querier.query_wasm_contract_info(&info.sender)?;
-    if contract_info.code_id != config.commerce_code_id {
-        return Err(ContractError::InvalidContract {
-            expected_code_id: config.commerce_code_id.to_string(),
-        });
+    // Verify that the sender is an approved commerce contract,
+    // either by address or by code ID
+    if !COMMERCE_CONTRACTS.has(deps.storage, &info.sender) {
+        let contract_info = deps.querier.query_wasm_contract_info(&info.sender)?;
+        if !COMMERCE_CODE_IDS.has(deps.storage, contract_info.code_id) {
+            return Err(ContractError::UnapprovedContract {
+                contract: info.sender.to_string(),
+            });
+        }
     }
 
     let pending_review = pending_reviews().may_load(deps.storage, peer.clone())?;
 
     // if there is a review that is expired, remove it
+    // Committed reviews stay until their reveal window ends,
+    // held reviews until the counterpart can no longer reveal theirs
     if let Some(pending_review) = pending_review {
-        if env.block.time < pending_review.expires_at {
+        let held = HELD_REVIEWS.has(deps.storage, &peer);
+        let open = if held {
+            awaiting_reveal(deps.storage, &env, &config, &pending_review)?
+        } else {
+            env.block.time < pending_review.deadline(config.review_interval)
+        };
+        if open {
             return Err(ContractError::AwaitingReview {
                 reviewer: reviewer.to_string(),
             });
         }
 
-        pending_reviews().remove(deps.storage, peer.clone())?;
+        if held {
+            release_review(deps.storage, &env, &config, pending_review)?;
+        } else {
+            pending_reviews().remove(deps.storage, peer.clone())?;
+        }
     }
 
     let expires_at = env.block.time.plus_seconds(config.review_interval);
@@ -174,7 +676,10 @@ pub fn execute_register_pending_review(
         reviewer: reviewer.clone(),
         commerce_contract: info.sender.clone(),
         order_id,
+        order_value,
+        peer_role: peer_role.clone(),
         expires_at,
+        commitment: None,
     };
 
     // Save a new pending review
@@ -186,6 +691,11 @@ pub fn execute_register_pending_review(
         .add_attribute("reviewer", reviewer.to_string())
         .add_attribute("commerce_contract", info.sender.to_string())
         .add_attribute("order_id", order_id.to_string())
+        .add_attribute(
+            "order_value",
+            order_value.map_or(String::from("none"), |value| value.to_string()),
+        )
+        .add_attribute("peer_role", peer_role.to_string())
         .add_attribute("expires_at", expires_at.to_string()))
 }
 
@@ -195,47 +705,398 @@ pub fn execute_review(
     env: Env,
     address: String,
     review: ReviewResult,
+    comment: Option<ReviewComment>,
 ) -> Result<Response, ContractError> {
     let api = deps.api;
     let peer = api.addr_validate(&address)?;
 
+    if let Some(comment) = &comment {
+        validate_review_comment(comment)?;
+    }
+
     let config = CONFIG.load(deps.storage)?;
 
     let pending_review = pending_reviews().load(deps.storage, peer.clone())?;
 
+    // Held reviews are final
+    if HELD_REVIEWS.has(deps.storage, &peer) {
+        return Err(ContractError::AlreadyReviewed {});
+    }
+
     // If there is an expired review, remove it
     if env.block.time >= pending_review.expires_at {
         pending_reviews().remove(deps.storage, peer.clone())?;
     }
 
     // Now that we've possibly removed an expired review, let's revalidate!
-    pending_reviews().load(deps.storage, peer.clone())?;
+    let pending_review = pending_reviews().load(deps.storage, peer.clone())?;
 
-    // If there isn't a trust account for the peer, create one
-    // If there is one, update the data
-    let trust_info = TRUST_INFO.load(deps.storage, &peer);
-    match trust_info {
-        Ok(trust_info) => {
-            let trust_data = TrustData {
-                stake_days: trust_info.data.stake_days,
-                stake_amount: trust_info.data.stake_amount,
-                prev_stake_amount: trust_info.data.prev_stake_amount,
-                rating: trust_info.data.rating
-                    + match review {
-                        ReviewResult::ThumbsUp => 1,
-                        ReviewResult::ThumbsDown => -1,
-                    },
-            };
+    // Only the reviewer the pending review was registered for can leave it
+    if info.sender != pending_review.reviewer {
+        return Err(ContractError::Unauthorized {});
+    }
 
-            TRUST_INFO.update(deps.storage, &peer, |info| match info {
-                Some(_) => {
-                    // Update trust score
-                    let score = calculate_trust_score(trust_data.clone(), config);
+    // Committed reviews can only be revealed
+    if pending_review.commitment.is_some() {
+        return Err(ContractError::AlreadyCommitted {});
+    }
 
-                    Ok(TrustInfo {
-                        score,
-                        data: trust_data,
-                    })
+    ensure_counterpart_committed(deps.storage, &env, &pending_review)?;
+
+    submit_review(
+        deps.storage,
+        &env,
+        &config,
+        pending_review,
+        HeldReview {
+            result: review,
+            comment,
+        },
+        "review",
+    )
+}
+
+pub fn execute_commit_review(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    address: String,
+    commitment: String,
+) -> Result<Response, ContractError> {
+    let api = deps.api;
+    let peer = api.addr_validate(&address)?;
+
+    validate_review_commitment(&commitment)?;
+
+    let pending_review = pending_reviews().load(deps.storage, peer.clone())?;
+
+    // Only the reviewer the pending review was registered for can commit to it
+    if info.sender != pending_review.reviewer {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if env.block.time >= pending_review.expires_at {
+        return Err(ContractError::ReviewWindowEnded {});
+    }
+
+    if pending_review.commitment.is_some() {
+        return Err(ContractError::AlreadyCommitted {});
+    }
+
+    if HELD_REVIEWS.has(deps.storage, &peer) {
+        return Err(ContractError::AlreadyReviewed {});
+    }
+
+    ensure_not_banned(deps.storage, &info.sender, env.block.time)?;
+    ensure_not_banned(deps.storage, &peer, env.block.time)?;
+
+    let pending_review = PendingReview {
+        commitment: Some(commitment.to_lowercase()),
+        ..pending_review
+    };
+    pending_reviews().save(deps.storage, peer.clone(), &pending_review)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "commit_review")
+        .add_attribute("peer", peer.to_string())
+        .add_attribute("reviewer", info.sender.to_string())
+        .add_attribute("order_id", pending_review.order_id.to_string()))
+}
+
+pub fn execute_reveal_review(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    address: String,
+    review: ReviewResult,
+    salt: String,
+    comment: Option<ReviewComment>,
+) -> Result<Response, ContractError> {
+    let api = deps.api;
+    let peer = api.addr_validate(&address)?;
+
+    if let Some(comment) = &comment {
+        validate_review_comment(comment)?;
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+
+    let pending_review = pending_reviews().load(deps.storage, peer.clone())?;
+
+    // Only the reviewer the pending review was registered for can reveal it
+    if info.sender != pending_review.reviewer {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let commitment = match &pending_review.commitment {
+        Some(commitment) => commitment,
+        None => return Err(ContractError::NotCommitted {}),
+    };
+
+    if HELD_REVIEWS.has(deps.storage, &peer) {
+        return Err(ContractError::AlreadyReviewed {});
+    }
+
+    // Unrevealed reviews count as no review once the reveal window ends
+    if env.block.time >= pending_review.deadline(config.review_interval) {
+        return Err(ContractError::ReviewWindowEnded {});
+    }
+
+    ensure_counterpart_committed(deps.storage, &env, &pending_review)?;
+
+    if review_commitment(&peer, &review, &salt) != *commitment {
+        return Err(ContractError::InvalidCommitment {});
+    }
+
+    submit_review(
+        deps.storage,
+        &env,
+        &config,
+        pending_review,
+        HeldReview {
+            result: review,
+            comment,
+        },
+        "reveal_review",
+    )
+}
+
+pub fn execute_settle_review(
+    deps: DepsMut,
+    env: Env,
+    address: String,
+) -> Result<Response, ContractError> {
+    let api = deps.api;
+    let peer = api.addr_validate(&address)?;
+
+    let config = CONFIG.load(deps.storage)?;
+
+    let pending_review = pending_reviews().load(deps.storage, peer.clone())?;
+
+    if !HELD_REVIEWS.has(deps.storage, &peer) {
+        return Err(ContractError::NoHeldReview {
+            peer: peer.to_string(),
+        });
+    }
+
+    if awaiting_reveal(deps.storage, &env, &config, &pending_review)? {
+        return Err(ContractError::AwaitingReveal {
+            counterpart: pending_review.peer.to_string(),
+        });
+    }
+
+    let reviewer = pending_review.reviewer.clone();
+    match release_review(deps.storage, &env, &config, pending_review)? {
+        Some(applied) => Ok(review_response("settle_review", applied)),
+        None => Ok(Response::new()
+            .add_attribute("action", "settle_review")
+            .add_attribute("peer", peer.to_string())
+            .add_attribute("reviewer", reviewer.to_string())
+            .add_attribute("dropped", "true")),
+    }
+}
+
+/// Counterpart of a mutual review, i.e. the pending review of the reviewer by the peer
+/// for the same order
+fn mutual_counterpart(
+    storage: &dyn Storage,
+    pending_review: &PendingReview,
+) -> StdResult<Option<PendingReview>> {
+    let counterpart = pending_reviews().may_load(storage, pending_review.reviewer.clone())?;
+    Ok(counterpart.filter(|counterpart| {
+        counterpart.reviewer == pending_review.peer
+            && counterpart.commerce_contract == pending_review.commerce_contract
+            && counterpart.order_id == pending_review.order_id
+    }))
+}
+
+/// Within the review window, a review can only be made public once the counterpart
+/// of a mutual review committed to theirs, so they can't base their review on it
+fn ensure_counterpart_committed(
+    storage: &dyn Storage,
+    env: &Env,
+    pending_review: &PendingReview,
+) -> Result<(), ContractError> {
+    if env.block.time >= pending_review.expires_at {
+        return Ok(());
+    }
+
+    // A held review is as good as a commitment
+    match mutual_counterpart(storage, pending_review)? {
+        Some(counterpart)
+            if counterpart.commitment.is_none()
+                && !HELD_REVIEWS.has(storage, &counterpart.peer) =>
+        {
+            Err(ContractError::AwaitingCommitment {
+                counterpart: pending_review.peer.to_string(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Whether the counterpart of a mutual review committed to a review
+/// they haven't revealed yet but still can
+fn awaiting_reveal(
+    storage: &dyn Storage,
+    env: &Env,
+    config: &Config,
+    pending_review: &PendingReview,
+) -> StdResult<bool> {
+    Ok(match mutual_counterpart(storage, pending_review)? {
+        Some(counterpart) => {
+            counterpart.commitment.is_some()
+                && !HELD_REVIEWS.has(storage, &counterpart.peer)
+                && env.block.time < counterpart.deadline(config.review_interval)
+        }
+        None => false,
+    })
+}
+
+/// Applies a review, unless the counterpart of a mutual review can still reveal theirs
+/// In that case it's held back until they do, and both are applied together
+fn submit_review(
+    storage: &mut dyn Storage,
+    env: &Env,
+    config: &Config,
+    pending_review: PendingReview,
+    review: HeldReview,
+    action: &str,
+) -> Result<Response, ContractError> {
+    let peer = pending_review.peer.clone();
+    let reviewer = pending_review.reviewer.clone();
+
+    if awaiting_reveal(storage, env, config, &pending_review)? {
+        // Reject reviews that couldn't be applied right away
+        review_weight(storage, env, config, &pending_review)?;
+
+        HELD_REVIEWS.save(storage, &peer, &review)?;
+
+        return Ok(Response::new()
+            .add_attribute("action", action)
+            .add_attribute("peer", peer.to_string())
+            .add_attribute("reviewer", reviewer.to_string())
+            .add_attribute("held", "true"));
+    }
+
+    // Release the counterpart's review, if it was held back for this one
+    let released = match mutual_counterpart(storage, &pending_review)? {
+        Some(counterpart) => release_review(storage, env, config, counterpart)?,
+        None => None,
+    };
+
+    let applied = apply_review(
+        storage,
+        env,
+        config,
+        pending_review,
+        review.result,
+        review.comment,
+    )?;
+
+    let response = review_response(action, applied);
+    Ok(match released {
+        Some(released) => {
+            response.add_attribute("counterpart_review_id", released.review.id.to_string())
+        }
+        None => response,
+    })
+}
+
+/// Applies the review held for a pending review, if any
+/// Held reviews that can no longer be applied, e.g. as either account got banned since,
+/// are dropped along with their pending review
+fn release_review(
+    storage: &mut dyn Storage,
+    env: &Env,
+    config: &Config,
+    pending_review: PendingReview,
+) -> Result<Option<AppliedReview>, ContractError> {
+    let peer = pending_review.peer.clone();
+    let review = match HELD_REVIEWS.may_load(storage, &peer)? {
+        Some(review) => review,
+        None => return Ok(None),
+    };
+    HELD_REVIEWS.remove(storage, &peer);
+
+    match apply_review(
+        storage,
+        env,
+        config,
+        pending_review,
+        review.result,
+        review.comment,
+    ) {
+        Ok(applied) => Ok(Some(applied)),
+        Err(ContractError::Banned { .. }) | Err(ContractError::IneligibleReviewer { .. }) => {
+            pending_reviews().remove(storage, peer)?;
+            Ok(None)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// A review applied to the peer's rating
+struct AppliedReview {
+    review: Review,
+    eligible: bool,
+    weight: Decimal,
+    new_score: Decimal,
+}
+
+/// Applies a review to the peer's rating, records it and clears the pending review
+fn apply_review(
+    storage: &mut dyn Storage,
+    env: &Env,
+    config: &Config,
+    pending_review: PendingReview,
+    review: ReviewResult,
+    comment: Option<ReviewComment>,
+) -> Result<AppliedReview, ContractError> {
+    let peer = pending_review.peer.clone();
+    let reviewer = pending_review.reviewer.clone();
+
+    let (eligible, weight) = review_weight(storage, env, config, &pending_review)?;
+    let rating_delta = calculate_rating_delta(&review, weight);
+
+    // Cap how much rating the reviewer can contribute to the peer in the current window
+    let (rating_delta, pair_counter) = apply_pair_review_limit(
+        PAIR_REVIEWS.may_load(storage, (&peer, &reviewer))?,
+        rating_delta,
+        &config.pair_review_limit,
+        env.block.time,
+    );
+    PAIR_REVIEWS.save(storage, (&peer, &reviewer), &pair_counter)?;
+
+    // Keep the rating received through the store apart from the combined one
+    let store_key = (&peer, &pending_review.commerce_contract);
+    let store_rating = add_store_rating_delta(
+        STORE_RATINGS
+            .may_load(storage, store_key)?
+            .unwrap_or_else(|| StoreRating::new(env.block.time)),
+        rating_delta,
+        config,
+        env.block.time,
+    );
+    STORE_RATINGS.save(storage, store_key, &store_rating)?;
+
+    // If there isn't a trust account for the peer, create one
+    // If there is one, update the data
+    let trust_info = trust_accounts().load(storage, &peer);
+    match trust_info {
+        Ok(trust_info) => {
+            let trust_data = refresh_trust_data(storage, trust_info.data, config, env.block.time)?;
+            let trust_data = add_rating_delta(trust_data, &pending_review.peer_role, rating_delta);
+
+            trust_accounts().update(storage, &peer, env.block.height, |info| match info {
+                Some(_) => {
+                    // Update trust score
+                    let score = calculate_trust_score(trust_data.clone(), config.clone());
+
+                    Ok(TrustInfo {
+                        score,
+                        data: trust_data,
+                    })
                 }
                 None => Err(StdError::NotFound {
                     kind: String::from("trust_account"),
@@ -248,47 +1109,967 @@ pub fn execute_review(
                     kind: String::from("trust::state::TrustInfo"),
                 })
             {
-                let trust_data = TrustData {
-                    stake_days: 0,
-                    stake_amount: Uint128::from(0u128),
-                    prev_stake_amount: Uint128::from(0u128),
-                    rating: match review {
-                        ReviewResult::ThumbsUp => 1,
-                        ReviewResult::ThumbsDown => -1,
-                    },
-                };
+                let trust_data = add_rating_delta(
+                    TrustData::new(env.block.time),
+                    &pending_review.peer_role,
+                    rating_delta,
+                );
 
-                let score = calculate_trust_score(trust_data.clone(), config);
+                let score = calculate_trust_score(trust_data.clone(), config.clone());
 
-                TRUST_INFO.save(
-                    deps.storage,
+                trust_accounts().save(
+                    storage,
                     &peer,
                     &TrustInfo {
                         score,
                         data: trust_data,
                     },
+                    env.block.height,
                 )?;
             }
         }
     }
 
     // Let's remove that pending review now that a review has been submitted
-    pending_reviews().remove(deps.storage, peer.clone())?;
+    pending_reviews().remove(storage, peer.clone())?;
+
+    // Keep a record of the review alongside its comment
+    let review_record = Review {
+        id: next_review_id(storage)?,
+        peer: peer.clone(),
+        reviewer: reviewer.clone(),
+        commerce_contract: pending_review.commerce_contract,
+        order_id: pending_review.order_id,
+        peer_role: pending_review.peer_role,
+        result: review,
+        rating_delta,
+        comment,
+        reply: None,
+        created_at: env.block.time,
+    };
+    reviews().save(storage, review_record.id, &review_record)?;
 
     // Query trust score for response
-    let trust_info = TRUST_INFO.load(deps.storage, &peer)?;
+    let trust_info = trust_accounts().load(storage, &peer)?;
 
-    Ok(Response::new()
-        .add_attribute("action", "review")
-        .add_attribute("peer", peer.to_string())
-        .add_attribute("reviewer", info.sender.to_string())
+    Ok(AppliedReview {
+        review: review_record,
+        eligible,
+        weight,
+        new_score: trust_info.score,
+    })
+}
+
+/// Checks that the review can be applied and weighs it
+/// Returns whether the reviewer is eligible and the weight of their review
+fn review_weight(
+    storage: &dyn Storage,
+    env: &Env,
+    config: &Config,
+    pending_review: &PendingReview,
+) -> Result<(bool, Decimal), ContractError> {
+    let peer = &pending_review.peer;
+    let reviewer = &pending_review.reviewer;
+
+    // Banned accounts can neither review nor be reviewed
+    ensure_not_banned(storage, reviewer, env.block.time)?;
+    ensure_not_banned(storage, peer, env.block.time)?;
+
+    // Weigh the review by what the reviewer's score is now, not when it was last stored
+    // Reviewers without a trust account are treated as an empty account
+    let reviewer_info = current_trust_info(storage, reviewer, config, env.block.time)?;
+
+    // Weight the review by the reviewer's trust score and the order's value,
+    // unless the reviewer doesn't meet the eligibility requirements
+    let unmet_requirement = unmet_reviewer_requirement(
+        &reviewer_info,
+        pending_review.order_value.unwrap_or_default(),
+        config,
+        env.block.time,
+    );
+    let eligible = unmet_requirement.is_none();
+    let weight = match unmet_requirement {
+        Some(requirement) => match config.reviewer_eligibility.ineligible_reviews {
+            IneligibleReviewPolicy::Reject => {
+                return Err(ContractError::IneligibleReviewer { requirement })
+            }
+            IneligibleReviewPolicy::ZeroWeight => Decimal::zero(),
+        },
+        None => calculate_review_weight(
+            reviewer_info.score,
+            pending_review.order_value,
+            &config.review_weight,
+        ),
+    };
+
+    Ok((eligible, weight))
+}
+
+fn review_response(action: &str, applied: AppliedReview) -> Response {
+    let review = applied.review;
+
+    Response::new()
+        .add_attribute("action", action)
+        .add_attribute("review_id", review.id.to_string())
+        .add_attribute("peer", review.peer.to_string())
+        .add_attribute("reviewer", review.reviewer.to_string())
         .add_attribute(
             "review",
-            (match review {
+            (match review.result {
                 ReviewResult::ThumbsUp => 1,
                 ReviewResult::ThumbsDown => -1,
             })
             .to_string(),
         )
-        .add_attribute("new_score", trust_info.score.to_string()))
+        .add_attribute("eligible", applied.eligible.to_string())
+        .add_attribute("weight", applied.weight.to_string())
+        .add_attribute("rating_delta", review.rating_delta.to_string())
+        .add_attribute("new_score", applied.new_score.to_string())
+}
+
+pub fn execute_reply_to_review(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: u64,
+    reply: ReviewComment,
+) -> Result<Response, ContractError> {
+    validate_review_comment(&reply)?;
+
+    reviews().update(deps.storage, id, |review| match review {
+        Some(review) => {
+            // Only the reviewed peer can reply to a review
+            if info.sender != review.peer {
+                return Err(ContractError::Unauthorized {});
+            }
+
+            if review.reply.is_some() {
+                return Err(ContractError::AlreadyReplied { review_id: id });
+            }
+
+            Ok(Review {
+                reply: Some(reply),
+                ..review
+            })
+        }
+        None => Err(ContractError::Std(StdError::NotFound {
+            kind: String::from("review"),
+        })),
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("action", "reply_to_review")
+        .add_attribute("review_id", id.to_string())
+        .add_attribute("peer", info.sender.to_string()))
+}
+
+pub fn execute_appeal_review(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    id: u64,
+    reason: Option<ReviewComment>,
+) -> Result<Response, ContractError> {
+    if let Some(reason) = &reason {
+        validate_review_comment(reason)?;
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let review = reviews().load(deps.storage, id)?;
+
+    // Only the reviewed peer can appeal a review
+    if info.sender != review.peer {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if review.result != ReviewResult::ThumbsDown {
+        return Err(ContractError::NotAppealable {});
+    }
+
+    if env.block.time >= review.created_at.plus_seconds(config.appeal_window) {
+        return Err(ContractError::AppealWindowEnded { review_id: id });
+    }
+
+    if appeals().may_load(deps.storage, id)?.is_some() {
+        return Err(ContractError::AlreadyAppealed { review_id: id });
+    }
+
+    // The review is suspended until the appeal is decided
+    let new_score = shift_review_rating(
+        deps.storage,
+        &env,
+        &config,
+        &review,
+        review.rating_delta,
+        false,
+    )?;
+
+    let appeal = Appeal {
+        review_id: id,
+        peer: review.peer,
+        rating_delta: review.rating_delta,
+        reason,
+        status: AppealStatus::Pending,
+        created_at: env.block.time,
+        decided_at: None,
+    };
+    appeals().save(deps.storage, id, &appeal)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "appeal_review")
+        .add_attribute("review_id", id.to_string())
+        .add_attribute("peer", info.sender.to_string())
+        .add_attribute("rating_delta", appeal.rating_delta.to_string())
+        .add_attribute("new_score", new_score.to_string()))
+}
+
+pub fn execute_decide_appeal(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    id: u64,
+    decision: AppealDecision,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let appeal = appeals().load(deps.storage, id)?;
+
+    if appeal.status != AppealStatus::Pending {
+        return Err(ContractError::AppealAlreadyDecided { review_id: id });
+    }
+
+    let review = reviews().load(deps.storage, id)?;
+
+    let status = match decision {
+        // The peer lost the dispute, so the review counts again
+        AppealDecision::Uphold => {
+            shift_review_rating(
+                deps.storage,
+                &env,
+                &config,
+                &review,
+                appeal.rating_delta,
+                true,
+            )?;
+            AppealStatus::Upheld
+        }
+        // The review was already reversed when it was appealed
+        AppealDecision::Strike => AppealStatus::Struck,
+    };
+
+    let appeal = Appeal {
+        status,
+        decided_at: Some(env.block.time),
+        ..appeal
+    };
+    appeals().save(deps.storage, id, &appeal)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "decide_appeal")
+        .add_attribute("review_id", id.to_string())
+        .add_attribute("peer", appeal.peer.to_string())
+        .add_attribute(
+            "struck",
+            (appeal.status == AppealStatus::Struck).to_string(),
+        ))
+}
+
+/// Removes a review's rating delta from the peer's rating and store rating, or restores it
+/// Returns the peer's new score
+fn shift_review_rating(
+    storage: &mut dyn Storage,
+    env: &Env,
+    config: &Config,
+    review: &Review,
+    rating_delta: i64,
+    restore: bool,
+) -> Result<Decimal, ContractError> {
+    let trust_info = trust_accounts().load(storage, &review.peer)?;
+    let data = refresh_trust_data(storage, trust_info.data, config, env.block.time)?;
+    let data = match restore {
+        true => restore_rating_delta(
+            data,
+            &review.peer_role,
+            rating_delta,
+            review.created_at,
+            config,
+            env.block.time,
+        ),
+        false => reverse_rating_delta(
+            data,
+            &review.peer_role,
+            rating_delta,
+            review.created_at,
+            config,
+            env.block.time,
+        ),
+    };
+
+    let store_key = (&review.peer, &review.commerce_contract);
+    if let Some(store_rating) = STORE_RATINGS.may_load(storage, store_key)? {
+        let store_rating = match restore {
+            true => restore_store_rating_delta(
+                store_rating,
+                rating_delta,
+                review.created_at,
+                config,
+                env.block.time,
+            ),
+            false => reverse_store_rating_delta(
+                store_rating,
+                rating_delta,
+                review.created_at,
+                config,
+                env.block.time,
+            ),
+        };
+        STORE_RATINGS.save(storage, store_key, &store_rating)?;
+    }
+
+    let score = calculate_trust_score(data.clone(), config.clone());
+    trust_accounts().save(
+        storage,
+        &review.peer,
+        &TrustInfo { score, data },
+        env.block.height,
+    )?;
+
+    Ok(score)
+}
+
+pub fn execute_add_attester(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+    name: String,
+    score_bonus: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let attester = deps.api.addr_validate(&address)?;
+
+    // Attestations already issued keep the bonus they were issued with
+    ATTESTERS.save(deps.storage, &attester, &Attester { name, score_bonus })?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_attester")
+        .add_attribute("sender", info.sender)
+        .add_attribute("attester", attester)
+        .add_attribute("score_bonus", score_bonus.to_string()))
+}
+
+pub fn execute_remove_attester(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let attester = deps.api.addr_validate(&address)?;
+
+    if !ATTESTERS.has(deps.storage, &attester) {
+        return Err(ContractError::Std(StdError::NotFound {
+            kind: String::from("attester"),
+        }));
+    }
+
+    ATTESTERS.remove(deps.storage, &attester);
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_attester")
+        .add_attribute("sender", info.sender)
+        .add_attribute("attester", attester))
+}
+
+pub fn execute_attest(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    address: String,
+    kind: String,
+    uri: Option<String>,
+    expires_at: Option<Timestamp>,
+) -> Result<Response, ContractError> {
+    let subject = deps.api.addr_validate(&address)?;
+
+    // Only approved attesters can issue attestations
+    let attester = match ATTESTERS.may_load(deps.storage, &info.sender)? {
+        Some(attester) => attester,
+        None => return Err(ContractError::Unauthorized {}),
+    };
+
+    validate_attestation(&kind, &uri, expires_at, env.block.time)?;
+
+    let config = CONFIG.load(deps.storage)?;
+
+    let data = trust_accounts()
+        .may_load(deps.storage, &subject)?
+        .map_or_else(|| TrustData::new(env.block.time), |info| info.data);
+    let mut data = refresh_trust_data(deps.storage, data, &config, env.block.time)?;
+
+    // Issuing an attestation of the same kind again replaces it
+    data.attestations
+        .retain(|attestation| attestation.attester != info.sender || attestation.kind != kind);
+    data.attestations.push(Attestation {
+        attester: info.sender.clone(),
+        kind: kind.clone(),
+        uri,
+        score_bonus: attester.score_bonus,
+        issued_at: env.block.time,
+        expires_at,
+    });
+
+    let score = calculate_trust_score(data.clone(), config);
+    trust_accounts().save(
+        deps.storage,
+        &subject,
+        &TrustInfo { score, data },
+        env.block.height,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "attest")
+        .add_attribute("attester", info.sender.to_string())
+        .add_attribute("subject", subject.to_string())
+        .add_attribute("kind", kind)
+        .add_attribute(
+            "expires_at",
+            expires_at.map_or(String::from("never"), |expires_at| expires_at.to_string()),
+        )
+        .add_attribute("new_score", score.to_string()))
+}
+
+pub fn execute_revoke_attestation(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    address: String,
+    kind: String,
+    attester: Option<String>,
+) -> Result<Response, ContractError> {
+    let api = deps.api;
+    let subject = api.addr_validate(&address)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    // Attesters revoke their own attestations, the admin can revoke anyone's
+    let attester = match attester {
+        Some(attester) => api.addr_validate(&attester)?,
+        None => info.sender.clone(),
+    };
+    if info.sender != attester && info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let trust_info = trust_accounts().load(deps.storage, &subject)?;
+    let mut data = refresh_trust_data(deps.storage, trust_info.data, &config, env.block.time)?;
+
+    let attestations = data.attestations.len();
+    data.attestations
+        .retain(|attestation| attestation.attester != attester || attestation.kind != kind);
+    if data.attestations.len() == attestations {
+        return Err(ContractError::Std(StdError::NotFound {
+            kind: String::from("attestation"),
+        }));
+    }
+
+    let score = calculate_trust_score(data.clone(), config);
+    trust_accounts().save(
+        deps.storage,
+        &subject,
+        &TrustInfo { score, data },
+        env.block.height,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "revoke_attestation")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("attester", attester.to_string())
+        .add_attribute("subject", subject.to_string())
+        .add_attribute("kind", kind)
+        .add_attribute("new_score", score.to_string()))
+}
+
+pub fn execute_slash(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    address: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let address = deps.api.addr_validate(&address)?;
+    let start_after = start_after
+        .map(|start_after| deps.api.addr_validate(&start_after))
+        .transpose()?;
+    let limit = limit.unwrap_or(DEFAULT_SLASH_LIMIT).min(MAX_SLASH_LIMIT) as usize;
+
+    // Vouchers are slashed a page at a time, so any amount of them can be
+    let (slashed, last_voucher) =
+        slash_vouches(deps.storage, &env, &config, &address, start_after, limit)?;
+    let last_voucher = match last_voucher {
+        Some(last_voucher) if !slashed.is_zero() => last_voucher,
+        _ => return Err(ContractError::NothingToSlash {}),
+    };
+
+    let msg = send_cw20_tokens(
+        &config.admin,
+        &Cw20Coin {
+            address: config.vouching.token.to_string(),
+            amount: slashed,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "slash")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("address", address.to_string())
+        .add_attribute("slashed", slashed.to_string())
+        .add_attribute("last_voucher", last_voucher.to_string())
+        .add_submessage(msg))
+}
+
+pub fn execute_ban(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    address: String,
+    reason: String,
+    expires_at: Option<Timestamp>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let address = deps.api.addr_validate(&address)?;
+
+    validate_ban(&reason, expires_at, env.block.time)?;
+
+    let ban = Ban {
+        reason: reason.clone(),
+        banned_at: env.block.time,
+        expires_at,
+    };
+    BANS.save(deps.storage, &address, &ban)?;
+
+    let data = trust_accounts()
+        .may_load(deps.storage, &address)?
+        .map_or_else(|| TrustData::new(env.block.time), |info| info.data);
+    let data = TrustData {
+        ban: Some(ban),
+        ..refresh_trust_data(deps.storage, data, &config, env.block.time)?
+    };
+    let score = calculate_trust_score(data.clone(), config);
+    trust_accounts().save(
+        deps.storage,
+        &address,
+        &TrustInfo { score, data },
+        env.block.height,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "ban")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("address", address.to_string())
+        .add_attribute("reason", reason)
+        .add_attribute(
+            "expires_at",
+            expires_at.map_or(String::from("never"), |expires_at| expires_at.to_string()),
+        ))
+}
+
+pub fn execute_unban(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    address: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let address = deps.api.addr_validate(&address)?;
+
+    if !BANS.has(deps.storage, &address) {
+        return Err(ContractError::NotBanned {
+            address: address.to_string(),
+        });
+    }
+    BANS.remove(deps.storage, &address);
+
+    let trust_info = trust_accounts().load(deps.storage, &address)?;
+    let data = TrustData {
+        ban: None,
+        ..refresh_trust_data(deps.storage, trust_info.data, &config, env.block.time)?
+    };
+    let score = calculate_trust_score(data.clone(), config);
+    trust_accounts().save(
+        deps.storage,
+        &address,
+        &TrustInfo { score, data },
+        env.block.height,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "unban")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("address", address.to_string())
+        .add_attribute("new_score", score.to_string()))
+}
+
+pub fn execute_receive(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    wrapper: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let msg: ReceiveMsg = from_binary(&wrapper.msg)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only the vouching token can be locked
+    if info.sender != config.vouching.token {
+        return Err(ContractError::InvalidToken {
+            token: info.sender.to_string(),
+        });
+    }
+
+    let sender = deps.api.addr_validate(&wrapper.sender)?;
+
+    match msg {
+        ReceiveMsg::Vouch { address } => {
+            execute_vouch(deps, env, config, sender, address, wrapper.amount)
+        }
+    }
+}
+
+pub fn execute_vouch(
+    deps: DepsMut,
+    env: Env,
+    config: Config,
+    voucher: Addr,
+    address: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let vouchee = deps.api.addr_validate(&address)?;
+
+    if voucher == vouchee {
+        return Err(ContractError::CannotVouchForSelf {});
+    }
+
+    // Vouchers must be established accounts, judged by their score as of now
+    // Vouchers without a trust account are treated as an empty account
+    let voucher_info = current_trust_info(deps.storage, &voucher, &config, env.block.time)?;
+    if let Some(requirement) = unmet_voucher_requirement(&voucher_info, &config, env.block.time) {
+        return Err(ContractError::IneligibleVoucher { requirement });
+    }
+    let weighted_amount = weighted_vouch_amount(amount, voucher_info.score, &config.vouching);
+
+    vouches().update(
+        deps.storage,
+        (&voucher, &vouchee),
+        |vouch| -> StdResult<_> {
+            Ok(match vouch {
+                Some(vouch) => Vouch {
+                    amount: vouch.amount + amount,
+                    weighted_amount: vouch.weighted_amount + weighted_amount,
+                    ..vouch
+                },
+                None => Vouch {
+                    voucher: voucher.clone(),
+                    vouchee: vouchee.clone(),
+                    amount,
+                    weighted_amount,
+                    created_at: env.block.time,
+                },
+            })
+        },
+    )?;
+
+    let data = trust_accounts()
+        .may_load(deps.storage, &vouchee)?
+        .map_or_else(|| TrustData::new(env.block.time), |info| info.data);
+    let data = refresh_trust_data(deps.storage, data, &config, env.block.time)?;
+    let data = TrustData {
+        vouched_amount: data.vouched_amount + weighted_amount,
+        ..data
+    };
+    let score = calculate_trust_score(data.clone(), config);
+    trust_accounts().save(
+        deps.storage,
+        &vouchee,
+        &TrustInfo { score, data },
+        env.block.height,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "vouch")
+        .add_attribute("voucher", voucher.to_string())
+        .add_attribute("vouchee", vouchee.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("weighted_amount", weighted_amount.to_string())
+        .add_attribute("new_score", score.to_string()))
+}
+
+pub fn execute_unvouch(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    address: String,
+    amount: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    let vouchee = deps.api.addr_validate(&address)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    let vouch = vouches().load(deps.storage, (&info.sender, &vouchee))?;
+
+    // Unvouch everything by default
+    let amount = amount.unwrap_or(vouch.amount);
+    if amount.is_zero() || amount > vouch.amount {
+        return Err(ContractError::InvalidUnvouchAmount {
+            vouched: vouch.amount,
+        });
+    }
+
+    let removed_amount = vouch.weighted_amount * Decimal::from_ratio(amount, vouch.amount);
+    match amount == vouch.amount {
+        true => vouches().remove(deps.storage, (&info.sender, &vouchee))?,
+        false => vouches().save(
+            deps.storage,
+            (&info.sender, &vouchee),
+            &Vouch {
+                amount: vouch.amount - amount,
+                weighted_amount: vouch.weighted_amount - removed_amount,
+                ..vouch
+            },
+        )?,
+    }
+
+    // Tokens already unbonding for the vouchee are merged into a single unbonding
+    let release_at = env
+        .block
+        .time
+        .plus_seconds(config.vouching.unbonding_period);
+    unbondings().update(
+        deps.storage,
+        (&info.sender, &vouchee),
+        |unbonding| -> StdResult<_> {
+            Ok(Unbonding {
+                voucher: info.sender.clone(),
+                vouchee: vouchee.clone(),
+                amount: unbonding.map_or(Uint128::zero(), |unbonding| unbonding.amount) + amount,
+                release_at,
+            })
+        },
+    )?;
+
+    let trust_info = trust_accounts().load(deps.storage, &vouchee)?;
+    let data = refresh_trust_data(deps.storage, trust_info.data, &config, env.block.time)?;
+    let data = TrustData {
+        vouched_amount: data
+            .vouched_amount
+            .checked_sub(removed_amount)
+            .unwrap_or_default(),
+        ..data
+    };
+    let score = calculate_trust_score(data.clone(), config);
+    trust_accounts().save(
+        deps.storage,
+        &vouchee,
+        &TrustInfo { score, data },
+        env.block.height,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "unvouch")
+        .add_attribute("voucher", info.sender.to_string())
+        .add_attribute("vouchee", vouchee.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("release_at", release_at.to_string())
+        .add_attribute("new_score", score.to_string()))
+}
+
+pub fn execute_claim_unvouched(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let released = unbondings()
+        .prefix(&info.sender)
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|res| match res {
+            Ok((_, unbonding)) => unbonding.release_at <= env.block.time,
+            Err(_) => true,
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let amount = released
+        .iter()
+        .fold(Uint128::zero(), |total, (_, unbonding)| {
+            total + unbonding.amount
+        });
+    if amount.is_zero() {
+        return Err(ContractError::NothingToClaim {});
+    }
+
+    for (vouchee, _) in released.iter() {
+        unbondings().remove(deps.storage, (&info.sender, vouchee))?;
+    }
+
+    let msg = send_cw20_tokens(
+        &info.sender,
+        &Cw20Coin {
+            address: config.vouching.token.to_string(),
+            amount,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "claim_unvouched")
+        .add_attribute("voucher", info.sender.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_submessage(msg))
+}
+
+/// Slashes `slash_share` of the tokens vouched for an address, including unbonding ones,
+/// for up to `limit` vouchers in order, starting after `start_after`
+/// Returns the amount of tokens slashed and the last voucher slashed
+fn slash_vouches(
+    storage: &mut dyn Storage,
+    env: &Env,
+    config: &Config,
+    vouchee: &Addr,
+    start_after: Option<Addr>,
+    limit: usize,
+) -> StdResult<(Uint128, Option<Addr>)> {
+    let share = config.vouching.slash_share;
+    let mut slashed = Uint128::zero();
+    let mut removed_amount = Decimal::zero();
+
+    // Vouchers can have a vouch, an unbonding or both, so the page is taken over both
+    let start = || {
+        start_after
+            .clone()
+            .map(|voucher| Bound::exclusive((voucher, vouchee.clone())))
+    };
+    let vouched = vouches()
+        .idx
+        .by_vouchee
+        .prefix(vouchee.clone())
+        .range(storage, start(), None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+    let unbonding = unbondings()
+        .idx
+        .by_vouchee
+        .prefix(vouchee.clone())
+        .range(storage, start(), None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut vouchers = vouched
+        .iter()
+        .map(|(_, vouch)| vouch.voucher.clone())
+        .chain(
+            unbonding
+                .iter()
+                .map(|(_, unbonding)| unbonding.voucher.clone()),
+        )
+        .collect::<Vec<_>>();
+    vouchers.sort();
+    vouchers.dedup();
+    vouchers.truncate(limit);
+    let last_voucher = match vouchers.last() {
+        Some(last_voucher) => last_voucher.clone(),
+        None => return Ok((slashed, None)),
+    };
+
+    for (_, vouch) in vouched {
+        if vouch.voucher > last_voucher {
+            break;
+        }
+
+        let amount = vouch.amount * share;
+        let weighted_amount = vouch.weighted_amount * share;
+        slashed += amount;
+        removed_amount += weighted_amount;
+
+        let key = (&vouch.voucher, &vouch.vouchee);
+        match amount == vouch.amount {
+            true => vouches().remove(storage, key)?,
+            false => vouches().save(
+                storage,
+                key,
+                &Vouch {
+                    amount: vouch.amount - amount,
+                    weighted_amount: vouch.weighted_amount - weighted_amount,
+                    ..vouch.clone()
+                },
+            )?,
+        }
+    }
+
+    for (_, unbonding) in unbonding {
+        if unbonding.voucher > last_voucher {
+            break;
+        }
+
+        let amount = unbonding.amount * share;
+        slashed += amount;
+
+        let key = (&unbonding.voucher, &unbonding.vouchee);
+        match amount == unbonding.amount {
+            true => unbondings().remove(storage, key)?,
+            false => unbondings().save(
+                storage,
+                key,
+                &Unbonding {
+                    amount: unbonding.amount - amount,
+                    ..unbonding.clone()
+                },
+            )?,
+        }
+    }
+
+    if !removed_amount.is_zero() {
+        let trust_info = trust_accounts().load(storage, vouchee)?;
+        let data = refresh_trust_data(storage, trust_info.data, config, env.block.time)?;
+        let data = TrustData {
+            vouched_amount: data
+                .vouched_amount
+                .checked_sub(removed_amount)
+                .unwrap_or_default(),
+            ..data
+        };
+        let score = calculate_trust_score(data.clone(), config.clone());
+        trust_accounts().save(
+            storage,
+            vouchee,
+            &TrustInfo { score, data },
+            env.block.height,
+        )?;
+    }
+
+    Ok((slashed, Some(last_voucher)))
 }
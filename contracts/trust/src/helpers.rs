@@ -1,7 +1,24 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Decimal, Uint128};
+use cosmwasm_std::{
+    to_binary, Addr, Api, Decimal, StdError, StdResult, Storage, SubMsg, Timestamp, Uint128,
+    WasmMsg,
+};
+use cw20::{Cw20Coin, Cw20ExecuteMsg};
+use sha2::{Digest, Sha256};
 
-use crate::state::{Config, TrustData};
+use crate::{
+    msg::{StakeSourceMsg, StakingSourceMsg, VouchingParamsMsg},
+    state::{
+        trust_accounts, Attestation, BayesianRatingModelParams, Config, IneligibleReviewPolicy,
+        LegacyConfig, LegacyPendingReview, LegacyTrustInfo, LinearModelParams, LogStakeModelParams,
+        PairReviewCounter, PairReviewLimit, PendingReview, ReviewComment, ReviewResult,
+        ReviewWeightParams, ReviewerEligibility, RoleRating, ScoreModel, SourceStake, StakeSource,
+        StakingSource, StoreRating, Tier, TradeRole, TrustData, TrustInfo, VouchingParams,
+        ATTESTERS, BANS, LEGACY_MAX_SCORE, MAX_ATTESTATION_KIND_LENGTH, MAX_COMMENT_LENGTH,
+        MAX_URI_LENGTH, RATING_PRECISION,
+    },
+    ContractError,
+};
 
 /// TrustContract is a wrapper around Addr that provides a lot of helpers
 /// for working with this.
@@ -14,49 +31,1249 @@ impl TrustContract {
     }
 }
 
+/// Scores are calculated as integers scaled by 10^12 to allow decimal-point math
+const SCORE_SCALE: u128 = 1_000_000_000_000;
+
+/// Components of a trust score, which add up to the score before it is capped
+#[cw_serde]
+pub struct ScoreBreakdown {
+    pub base: ScoreComponent,
+    pub rating: ScoreComponent,
+    pub stake: ScoreComponent,
+    /// Only used by the linear model, lowers the score for a positive rating
+    pub rating_floor: ScoreComponent,
+    /// Bonus from attestations, the highest one of each attester
+    pub attestations: ScoreComponent,
+    /// Bonus from the tokens vouched for the account, up to `VouchingParams::max_bonus`
+    pub vouches: ScoreComponent,
+    pub max_score: Decimal,
+    /// Sum of the components, capped between 0 and `max_score`
+    /// Always 0 while the account is banned
+    pub score: Decimal,
+}
+
+/// How much a component adds to, or removes from the score
+#[cw_serde]
+pub struct ScoreComponent {
+    pub value: Decimal,
+    pub negative: bool,
+}
+
+impl ScoreComponent {
+    fn from_scaled(value: i128) -> Self {
+        ScoreComponent {
+            value: Decimal::from_atomics(value.unsigned_abs(), 12).unwrap(),
+            negative: value < 0,
+        }
+    }
+}
+
+/// Components of a score, scaled by `SCORE_SCALE`
+struct ScaledComponents {
+    base: i128,
+    rating: i128,
+    stake: i128,
+    rating_floor: i128,
+}
+
 pub fn calculate_trust_score(trust_data: TrustData, config: Config) -> Decimal {
-    // t = 500 + 25R + ( T / 250 ) * D - 14 * ( R / 10 )
-    // https://medium.com/@swiftprotocol/the-case-for-decentralized-trust-in-modern-blockchain-systems-9ec69a184d1c
-    // See thumbs-up/thumbs-down approach section of this article
+    calculate_score_breakdown(&trust_data, &config).score
+}
+
+/// Trust score with the rating earned in a single role in place of the combined rating
+/// Falls back to the combined rating if the role isn't known
+pub fn calculate_role_score(trust_data: &TrustData, role: &TradeRole, config: &Config) -> Decimal {
+    let role_rating = match trust_data.role_rating(role) {
+        Some(role_rating) => role_rating,
+        None => return calculate_score_breakdown(trust_data, config).score,
+    };
+    let trust_data = TrustData {
+        rating: role_rating.rating,
+        decayed_rating: role_rating.decayed_rating,
+        rating_weight: role_rating.rating_weight,
+        decayed_rating_weight: role_rating.decayed_rating_weight,
+        ..trust_data.clone()
+    };
 
-    // We multiply everything by 1_000_000_000_000 to allow us to do decimal-point math.
+    calculate_score_breakdown(&trust_data, config).score
+}
 
-    let params = config.trust_score_params;
+/// Trust score with the rating received through a commerce contract in place of the combined rating
+pub fn calculate_store_score(
+    trust_data: &TrustData,
+    store_rating: &StoreRating,
+    config: &Config,
+) -> Decimal {
+    let trust_data = TrustData {
+        rating: store_rating.rating,
+        decayed_rating: store_rating.decayed_rating,
+        rating_weight: store_rating.rating_weight,
+        decayed_rating_weight: store_rating.decayed_rating_weight,
+        ..trust_data.clone()
+    };
 
-    let base_score = params.base_score * 1_000_000_000_000;
+    calculate_score_breakdown(&trust_data, config).score
+}
 
-    // Rating
-    let rating = trust_data.rating * 1_000_000_000_000;
+pub fn calculate_score_breakdown(trust_data: &TrustData, config: &Config) -> ScoreBreakdown {
+    // Rating, with older reviews counting less if rating decay is enabled
+    let (rating, rating_weight) = match config.rating_half_life {
+        0 => (trust_data.rating, trust_data.rating_weight),
+        _ => (trust_data.decayed_rating, trust_data.decayed_rating_weight),
+    };
 
-    // Stake info
-    let stake_amount = (match trust_data.stake_amount > config.max_staked_tokens {
-        true => config.max_staked_tokens,
-        false => trust_data.stake_amount,
-    }
-    .u128()
-        / params.denom_multiplier)
-        * 1_000_000_000_000;
+    // Stake info, aggregated over all staking sources
+    let stake_amount = weighted_stake_amount(trust_data, &config.staking_sources)
+        .atomics()
+        .u128()
+        / 1_000_000;
 
     let stake_days = match trust_data.stake_days > config.max_staked_days {
         true => config.max_staked_days,
         false => trust_data.stake_days,
     };
 
-    let rating_comp = params.rating_multiplier as i64 * rating;
-    let staked_comp = stake_amount / params.stake_amount_denominator as u128 * stake_days as u128;
-    let rating_floor_comp =
-        params.min_stake_days as i64 * (rating / params.rating_floor_denominator as i64);
+    let (components, max_score) = match &config.score_model {
+        ScoreModel::Linear(params) => (
+            linear_score(params, rating, stake_amount, stake_days),
+            params.max_score,
+        ),
+        ScoreModel::LogStake(params) => (
+            log_stake_score(params, rating, stake_amount, stake_days),
+            params.max_score,
+        ),
+        ScoreModel::BayesianRating(params) => (
+            bayesian_rating_score(params, rating, rating_weight, stake_amount, stake_days),
+            params.max_score,
+        ),
+    };
+
+    let attestations = attestation_bonus(&trust_data.attestations) as i128 * SCORE_SCALE as i128;
 
-    let score =
-        base_score as i128 + rating_comp as i128 + staked_comp as i128 - rating_floor_comp as i128;
+    let vouches = vouch_bonus(trust_data.vouched_amount, &config.vouching);
 
-    let trust_score = match score < 0 {
+    let score = components.base
+        + components.rating
+        + components.stake
+        + components.rating_floor
+        + attestations
+        + vouches;
+
+    let max_score = max_score as i128 * SCORE_SCALE as i128;
+    let trust_score = match trust_data.ban.is_some() || score < 0 {
         true => 0,
-        false => match score > 1500 * 1_000_000_000_000 {
-            true => 1500 * 1_000_000_000_000,
+        false => match score > max_score {
+            true => max_score,
             false => score,
         },
     };
 
-    Decimal::from_atomics(Uint128::from(trust_score as u128), 12).unwrap()
+    ScoreBreakdown {
+        base: ScoreComponent::from_scaled(components.base),
+        rating: ScoreComponent::from_scaled(components.rating),
+        stake: ScoreComponent::from_scaled(components.stake),
+        rating_floor: ScoreComponent::from_scaled(components.rating_floor),
+        attestations: ScoreComponent::from_scaled(attestations),
+        vouches: ScoreComponent::from_scaled(vouches),
+        max_score: Decimal::from_atomics(max_score as u128, 12).unwrap(),
+        score: Decimal::from_atomics(Uint128::from(trust_score as u128), 12).unwrap(),
+    }
+}
+
+/// Sum of the highest score bonus of each attester
+fn attestation_bonus(attestations: &[Attestation]) -> u64 {
+    let mut bonuses: Vec<(&Addr, u64)> = vec![];
+
+    for attestation in attestations {
+        match bonuses
+            .iter_mut()
+            .find(|(attester, _)| **attester == attestation.attester)
+        {
+            Some((_, bonus)) => *bonus = (*bonus).max(attestation.score_bonus),
+            None => bonuses.push((&attestation.attester, attestation.score_bonus)),
+        }
+    }
+
+    // Simulated accounts can carry any bonuses, so saturate instead of overflowing
+    bonuses
+        .iter()
+        .fold(0u64, |total, (_, bonus)| total.saturating_add(*bonus))
+}
+
+/// Score added by vouches, scaled by `SCORE_SCALE`
+fn vouch_bonus(vouched_amount: Decimal, params: &VouchingParams) -> i128 {
+    // Simulated accounts can carry any vouched amount, and the bonus is capped anyway
+    let bonus = vouched_amount
+        .checked_mul(params.score_per_token)
+        .unwrap_or(Decimal::MAX)
+        .atomics()
+        .u128()
+        / 1_000_000;
+
+    bonus.min(params.max_bonus as u128 * SCORE_SCALE) as i128
+}
+
+/// Amount of whole tokens vouched, weighted by the voucher's score
+pub fn weighted_vouch_amount(
+    amount: Uint128,
+    voucher_score: Decimal,
+    params: &VouchingParams,
+) -> Decimal {
+    let tokens = Decimal::from_ratio(amount, params.denom_multiplier);
+
+    // A reference of zero disables the weighting
+    match params.reference_score {
+        0 => tokens,
+        reference_score => tokens * voucher_score * Decimal::from_ratio(1u64, reference_score),
+    }
+}
+
+fn linear_score(
+    params: &LinearModelParams,
+    rating: i64,
+    stake_amount: u128,
+    stake_days: u64,
+) -> ScaledComponents {
+    // t = 500 + 25R + ( T / 250 ) * D - 14 * ( R / 10 )
+    // https://medium.com/@swiftprotocol/the-case-for-decentralized-trust-in-modern-blockchain-systems-9ec69a184d1c
+    // See thumbs-up/thumbs-down approach section of this article
+
+    let base_score = params.base_score as i128 * SCORE_SCALE as i128;
+    let rating = rating as i128 * (SCORE_SCALE as i128 / RATING_PRECISION as i128);
+
+    let rating_comp = params.rating_multiplier as i128 * rating;
+    let staked_comp = stake_amount / params.stake_amount_denominator as u128 * stake_days as u128;
+    let rating_floor_comp =
+        params.min_stake_days as i128 * (rating / params.rating_floor_denominator as i128);
+
+    ScaledComponents {
+        base: base_score,
+        rating: rating_comp,
+        stake: staked_comp as i128,
+        rating_floor: -rating_floor_comp,
+    }
+}
+
+fn log_stake_score(
+    params: &LogStakeModelParams,
+    rating: i64,
+    stake_amount: u128,
+    stake_days: u64,
+) -> ScaledComponents {
+    let base_score = params.base_score as i128 * SCORE_SCALE as i128;
+    let rating = rating as i128 * (SCORE_SCALE as i128 / RATING_PRECISION as i128);
+
+    let rating_comp = params.rating_multiplier as i128 * rating;
+    let stake = stake_amount * stake_days as u128 / params.stake_amount_denominator as u128;
+    let staked_comp = params.stake_multiplier as u128 * log2(SCORE_SCALE + stake);
+
+    ScaledComponents {
+        base: base_score,
+        rating: rating_comp,
+        stake: staked_comp as i128,
+        rating_floor: 0,
+    }
+}
+
+fn bayesian_rating_score(
+    params: &BayesianRatingModelParams,
+    rating: i64,
+    rating_weight: u64,
+    stake_amount: u128,
+    stake_days: u64,
+) -> ScaledComponents {
+    let base_score = params.base_score as i128 * SCORE_SCALE as i128;
+
+    // Weight of the positive reviews, as the rating is positive minus negative reviews
+    let positive_weight = (rating as i128 + rating_weight as i128).max(0) as u128 / 2;
+
+    let prior_weight = params.prior_weight as u128 * RATING_PRECISION as u128;
+    let prior_share = params.prior_share.atomics().u128() / 1_000_000;
+
+    // Share of positive reviews, including the prior
+    let share = match prior_weight + rating_weight as u128 {
+        0 => prior_share,
+        total_weight => (prior_weight * prior_share + positive_weight * SCORE_SCALE) / total_weight,
+    };
+
+    let rating_comp = params.rating_range as i128 * (2 * share as i128 - SCORE_SCALE as i128);
+    let staked_comp = stake_amount / params.stake_amount_denominator as u128 * stake_days as u128;
+
+    ScaledComponents {
+        base: base_score,
+        rating: rating_comp,
+        stake: staked_comp as i128,
+        rating_floor: 0,
+    }
+}
+
+/// Base 2 logarithm of a number scaled by 10^12, which must be at least 1
+fn log2(x: u128) -> u128 {
+    let mut y = x;
+    let mut result = 0;
+
+    // Integer part
+    while y >= 2 * SCORE_SCALE {
+        y /= 2;
+        result += SCORE_SCALE;
+    }
+
+    // Fractional part, one bit at a time
+    for bit in 1..=40 {
+        y = y * y / SCORE_SCALE;
+        if y >= 2 * SCORE_SCALE {
+            y /= 2;
+            result += SCORE_SCALE >> bit;
+        }
+    }
+
+    result
+}
+
+/// 2^(-1/2^k) for k = 1..=16, scaled by 10^18
+const HALF_LIFE_ROOTS: [u128; 16] = [
+    707_106_781_186_547_524,
+    840_896_415_253_714_543,
+    917_004_043_204_671_231,
+    957_603_280_698_573_646,
+    978_572_062_087_700_134,
+    989_228_013_193_975_484,
+    994_599_423_483_633_175,
+    997_296_056_085_470_126,
+    998_647_112_890_970_173,
+    999_323_327_502_650_752,
+    999_661_606_496_243_683,
+    999_830_788_931_929_063,
+    999_915_390_886_613_497,
+    999_957_694_548_431_132,
+    999_978_847_050_491_929,
+    999_989_423_469_314_464,
+];
+
+/// Decays a rating by half for every `half_life` seconds elapsed
+pub fn decay_rating(rating: i64, elapsed: u64, half_life: u64) -> i64 {
+    if half_life == 0 {
+        return rating;
+    }
+
+    // Whole half-lives
+    let halvings = elapsed / half_life;
+    if halvings >= 63 {
+        return 0;
+    }
+    let rating = rating / (1i64 << halvings);
+
+    // Remaining fraction of a half-life, as a 16-bit fixed point number
+    let fraction = ((elapsed % half_life) as u128) * (1 << 16) / half_life as u128;
+
+    let mut factor: u128 = 1_000_000_000_000_000_000;
+    for (bit, root) in HALF_LIFE_ROOTS.iter().enumerate() {
+        if fraction & (1 << (15 - bit)) != 0 {
+            factor = factor * root / 1_000_000_000_000_000_000;
+        }
+    }
+
+    (rating as i128 * factor as i128 / 1_000_000_000_000_000_000) as i64
+}
+
+fn decay_role_rating(role_rating: RoleRating, elapsed: u64, half_life: u64) -> RoleRating {
+    RoleRating {
+        decayed_rating: decay_rating(role_rating.decayed_rating, elapsed, half_life),
+        decayed_rating_weight: decay_rating(
+            role_rating.decayed_rating_weight as i64,
+            elapsed,
+            half_life,
+        ) as u64,
+        ..role_rating
+    }
+}
+
+fn with_role_rating(trust_data: TrustData, role: &TradeRole, role_rating: RoleRating) -> TrustData {
+    match role {
+        TradeRole::Buyer => TrustData {
+            buyer_rating: role_rating,
+            ..trust_data
+        },
+        TradeRole::Seller => TrustData {
+            seller_rating: role_rating,
+            ..trust_data
+        },
+        TradeRole::Unknown => trust_data,
+    }
+}
+
+/// Adds a review's rating delta to a peer's combined rating and to the rating of their role
+pub fn add_rating_delta(trust_data: TrustData, role: &TradeRole, rating_delta: i64) -> TrustData {
+    let role_rating = trust_data.role_rating(role).cloned().unwrap_or_default();
+    let role_rating = RoleRating {
+        rating: role_rating.rating + rating_delta,
+        decayed_rating: role_rating.decayed_rating + rating_delta,
+        rating_weight: role_rating.rating_weight + rating_delta.unsigned_abs(),
+        decayed_rating_weight: role_rating.decayed_rating_weight + rating_delta.unsigned_abs(),
+    };
+    let trust_data = with_role_rating(trust_data, role, role_rating);
+
+    TrustData {
+        rating: trust_data.rating + rating_delta,
+        decayed_rating: trust_data.decayed_rating + rating_delta,
+        rating_weight: trust_data.rating_weight + rating_delta.unsigned_abs(),
+        decayed_rating_weight: trust_data.decayed_rating_weight + rating_delta.unsigned_abs(),
+        ..trust_data
+    }
+}
+
+/// Brings the decayed rating of a store rating up to date
+pub fn refresh_store_rating(
+    store_rating: StoreRating,
+    config: &Config,
+    now: Timestamp,
+) -> StoreRating {
+    let elapsed = now
+        .seconds()
+        .saturating_sub(store_rating.rating_updated_at.seconds());
+
+    StoreRating {
+        decayed_rating: decay_rating(
+            store_rating.decayed_rating,
+            elapsed,
+            config.rating_half_life,
+        ),
+        decayed_rating_weight: decay_rating(
+            store_rating.decayed_rating_weight as i64,
+            elapsed,
+            config.rating_half_life,
+        ) as u64,
+        rating_updated_at: now,
+        ..store_rating
+    }
+}
+
+/// Adds a review's rating delta to the rating a peer received through a commerce contract
+pub fn add_store_rating_delta(
+    store_rating: StoreRating,
+    rating_delta: i64,
+    config: &Config,
+    now: Timestamp,
+) -> StoreRating {
+    let store_rating = refresh_store_rating(store_rating, config, now);
+
+    StoreRating {
+        rating: store_rating.rating + rating_delta,
+        decayed_rating: store_rating.decayed_rating + rating_delta,
+        rating_weight: store_rating.rating_weight + rating_delta.unsigned_abs(),
+        decayed_rating_weight: store_rating.decayed_rating_weight + rating_delta.unsigned_abs(),
+        reviews: store_rating.reviews + 1,
+        ..store_rating
+    }
+}
+
+/// Removes a review's rating delta from the rating a peer received through a commerce contract
+/// Struck reviews still count towards `reviews`, as they do in the review history
+pub fn reverse_store_rating_delta(
+    store_rating: StoreRating,
+    rating_delta: i64,
+    reviewed_at: Timestamp,
+    config: &Config,
+    now: Timestamp,
+) -> StoreRating {
+    shift_store_rating_delta(store_rating, rating_delta, reviewed_at, config, now, false)
+}
+
+/// Adds back a review's rating delta removed by `reverse_store_rating_delta`
+pub fn restore_store_rating_delta(
+    store_rating: StoreRating,
+    rating_delta: i64,
+    reviewed_at: Timestamp,
+    config: &Config,
+    now: Timestamp,
+) -> StoreRating {
+    shift_store_rating_delta(store_rating, rating_delta, reviewed_at, config, now, true)
+}
+
+fn shift_store_rating_delta(
+    store_rating: StoreRating,
+    rating_delta: i64,
+    reviewed_at: Timestamp,
+    config: &Config,
+    now: Timestamp,
+    restore: bool,
+) -> StoreRating {
+    let store_rating = refresh_store_rating(store_rating, config, now);
+
+    let elapsed = now.seconds().saturating_sub(reviewed_at.seconds());
+    let decayed_delta = decay_rating(rating_delta, elapsed, config.rating_half_life);
+
+    let shifted = shift_rating(
+        RoleRating {
+            rating: store_rating.rating,
+            decayed_rating: store_rating.decayed_rating,
+            rating_weight: store_rating.rating_weight,
+            decayed_rating_weight: store_rating.decayed_rating_weight,
+        },
+        rating_delta,
+        decayed_delta,
+        restore,
+    );
+
+    StoreRating {
+        rating: shifted.rating,
+        decayed_rating: shifted.decayed_rating,
+        rating_weight: shifted.rating_weight,
+        decayed_rating_weight: shifted.decayed_rating_weight,
+        ..store_rating
+    }
+}
+
+/// Removes a review's rating delta from a peer's trust data, refreshed as of `now`
+/// The decayed rating loses whatever is left of the delta after decaying since `reviewed_at`
+pub fn reverse_rating_delta(
+    trust_data: TrustData,
+    role: &TradeRole,
+    rating_delta: i64,
+    reviewed_at: Timestamp,
+    config: &Config,
+    now: Timestamp,
+) -> TrustData {
+    shift_rating_delta(
+        trust_data,
+        role,
+        rating_delta,
+        reviewed_at,
+        config,
+        now,
+        false,
+    )
+}
+
+/// Adds back a review's rating delta removed by `reverse_rating_delta`
+/// The decayed rating gets whatever is left of the delta after decaying since `reviewed_at`
+pub fn restore_rating_delta(
+    trust_data: TrustData,
+    role: &TradeRole,
+    rating_delta: i64,
+    reviewed_at: Timestamp,
+    config: &Config,
+    now: Timestamp,
+) -> TrustData {
+    shift_rating_delta(
+        trust_data,
+        role,
+        rating_delta,
+        reviewed_at,
+        config,
+        now,
+        true,
+    )
+}
+
+fn shift_rating_delta(
+    trust_data: TrustData,
+    role: &TradeRole,
+    rating_delta: i64,
+    reviewed_at: Timestamp,
+    config: &Config,
+    now: Timestamp,
+    restore: bool,
+) -> TrustData {
+    let elapsed = now.seconds().saturating_sub(reviewed_at.seconds());
+    let decayed_delta = decay_rating(rating_delta, elapsed, config.rating_half_life);
+
+    let role_rating = shift_rating(
+        trust_data.role_rating(role).cloned().unwrap_or_default(),
+        rating_delta,
+        decayed_delta,
+        restore,
+    );
+    let trust_data = with_role_rating(trust_data, role, role_rating);
+
+    let shifted = shift_rating(
+        RoleRating {
+            rating: trust_data.rating,
+            decayed_rating: trust_data.decayed_rating,
+            rating_weight: trust_data.rating_weight,
+            decayed_rating_weight: trust_data.decayed_rating_weight,
+        },
+        rating_delta,
+        decayed_delta,
+        restore,
+    );
+
+    TrustData {
+        rating: shifted.rating,
+        decayed_rating: shifted.decayed_rating,
+        rating_weight: shifted.rating_weight,
+        decayed_rating_weight: shifted.decayed_rating_weight,
+        ..trust_data
+    }
+}
+
+/// Removes a rating delta and its weight from a rating, or restores them
+fn shift_rating(
+    rating: RoleRating,
+    rating_delta: i64,
+    decayed_delta: i64,
+    restore: bool,
+) -> RoleRating {
+    match restore {
+        true => RoleRating {
+            rating: rating.rating + rating_delta,
+            decayed_rating: rating.decayed_rating + decayed_delta,
+            rating_weight: rating.rating_weight + rating_delta.unsigned_abs(),
+            decayed_rating_weight: rating.decayed_rating_weight + decayed_delta.unsigned_abs(),
+        },
+        false => RoleRating {
+            rating: rating.rating - rating_delta,
+            decayed_rating: rating.decayed_rating - decayed_delta,
+            rating_weight: rating
+                .rating_weight
+                .saturating_sub(rating_delta.unsigned_abs()),
+            decayed_rating_weight: rating
+                .decayed_rating_weight
+                .saturating_sub(decayed_delta.unsigned_abs()),
+        },
+    }
+}
+
+/// Amount of whole tokens staked across all staking sources,
+/// each capped at the source's `max_staked_tokens` and weighted by its `weight`
+/// Stakes in contracts that are no longer a staking source are ignored
+pub fn weighted_stake_amount(trust_data: &TrustData, sources: &[StakingSource]) -> Decimal {
+    sources
+        .iter()
+        .map(|source| weighted_source_amount(source, trust_data.stake_amount(&source.source)))
+        .fold(Decimal::zero(), |total, amount| total + amount)
+}
+
+/// Amount of whole tokens staked in a staking source, capped and weighted
+pub fn weighted_source_amount(source: &StakingSource, stake_amount: Uint128) -> Decimal {
+    let stake_amount = match stake_amount > source.max_staked_tokens {
+        true => source.max_staked_tokens,
+        false => stake_amount,
+    };
+
+    Decimal::from_ratio(stake_amount, source.denom_multiplier) * source.weight
+}
+
+/// Records newly observed stake amounts, leaving other sources untouched
+/// The stake timer starts when tokens are first staked and resets on any decrease
+pub fn update_stake(
+    trust_data: TrustData,
+    observed: Vec<SourceStake>,
+    now: Timestamp,
+) -> TrustData {
+    let mut stakes = trust_data.stakes;
+    let mut decreased = false;
+
+    for stake in observed {
+        match stakes.iter_mut().find(|s| s.source == stake.source) {
+            Some(existing) => {
+                decreased |= stake.amount < existing.amount;
+                existing.amount = stake.amount;
+            }
+            None => stakes.push(stake),
+        }
+    }
+    stakes.retain(|stake| !stake.amount.is_zero());
+
+    let stake_start = match trust_data.stake_start {
+        _ if stakes.is_empty() => None,
+        Some(_) if decreased => Some(now),
+        Some(stake_start) => Some(stake_start),
+        None => Some(now),
+    };
+
+    TrustData {
+        stake_start,
+        stakes,
+        ..trust_data
+    }
+}
+
+/// Validates vouching parameters given in a message
+pub fn validate_vouching_params(
+    api: &dyn Api,
+    params: VouchingParamsMsg,
+) -> StdResult<VouchingParams> {
+    if params.denom_multiplier == 0 {
+        return Err(StdError::generic_err(
+            "Vouching denom_multiplier must be greater than zero",
+        ));
+    }
+    if params.slash_share > Decimal::one() {
+        return Err(StdError::generic_err(
+            "Vouching slash_share must not be greater than one",
+        ));
+    }
+
+    Ok(VouchingParams {
+        token: api.addr_validate(&params.token)?,
+        denom_multiplier: params.denom_multiplier,
+        score_per_token: params.score_per_token,
+        reference_score: params.reference_score,
+        max_bonus: params.max_bonus,
+        unbonding_period: params.unbonding_period,
+        slash_share: params.slash_share,
+        voucher_eligibility: params.voucher_eligibility,
+    })
+}
+
+/// Validates tiers, which must have distinct names and strictly ascending thresholds
+pub fn validate_tiers(tiers: Vec<Tier>) -> StdResult<Vec<Tier>> {
+    for (i, tier) in tiers.iter().enumerate() {
+        if tier.name.is_empty() {
+            return Err(StdError::generic_err("Tier names must not be empty"));
+        }
+        if tiers[..i].iter().any(|previous| previous.name == tier.name) {
+            return Err(StdError::generic_err(format!(
+                "Duplicate tier name: {}",
+                tier.name
+            )));
+        }
+        if i > 0 && tier.min_score <= tiers[i - 1].min_score {
+            return Err(StdError::generic_err(
+                "Tier thresholds must be in strictly ascending order",
+            ));
+        }
+    }
+
+    Ok(tiers)
+}
+
+/// Validates the score model, whose denominators must not be zero
+pub fn validate_score_model(score_model: ScoreModel) -> StdResult<ScoreModel> {
+    let non_zero = |value: u64, name: &str| match value {
+        0 => Err(StdError::generic_err(format!(
+            "Score model {} must be greater than zero",
+            name
+        ))),
+        _ => Ok(()),
+    };
+
+    match &score_model {
+        ScoreModel::Linear(params) => {
+            non_zero(params.stake_amount_denominator, "stake_amount_denominator")?;
+            non_zero(params.rating_floor_denominator, "rating_floor_denominator")?;
+        }
+        ScoreModel::LogStake(params) => {
+            non_zero(params.stake_amount_denominator, "stake_amount_denominator")?;
+        }
+        ScoreModel::BayesianRating(params) => {
+            non_zero(params.stake_amount_denominator, "stake_amount_denominator")?;
+            if params.prior_share > Decimal::one() {
+                return Err(StdError::generic_err(
+                    "Score model prior_share must not be greater than one",
+                ));
+            }
+        }
+    }
+
+    Ok(score_model)
+}
+
+/// Validates review weights, whose bounds must not be inverted
+pub fn validate_review_weight(params: ReviewWeightParams) -> StdResult<ReviewWeightParams> {
+    if params.min_weight > params.max_weight {
+        return Err(StdError::generic_err(
+            "Review min_weight must not be greater than max_weight",
+        ));
+    }
+
+    Ok(params)
+}
+
+/// Highest tier whose threshold the score reaches, if any
+pub fn tier_for_score(score: Decimal, tiers: &[Tier]) -> Option<Tier> {
+    tiers
+        .iter()
+        .rev()
+        .find(|tier| score >= Decimal::from_ratio(tier.min_score, 1u64))
+        .cloned()
+}
+
+pub fn send_cw20_tokens(to: &Addr, balance: &Cw20Coin) -> StdResult<SubMsg> {
+    let msg = Cw20ExecuteMsg::Transfer {
+        recipient: to.into(),
+        amount: balance.amount,
+    };
+    let exec = SubMsg::new(WasmMsg::Execute {
+        contract_addr: balance.address.to_string(),
+        msg: to_binary(&msg)?,
+        funds: vec![],
+    });
+
+    Ok(exec)
+}
+
+/// Validates staking sources given in a message
+pub fn validate_staking_sources(
+    api: &dyn Api,
+    sources: Vec<StakingSourceMsg>,
+) -> StdResult<Vec<StakingSource>> {
+    let mut validated: Vec<StakingSource> = vec![];
+
+    for source in sources {
+        let stake_source = match source.source {
+            StakeSourceMsg::Cw20Stake { contract } => StakeSource::Cw20Stake {
+                contract: api.addr_validate(&contract)?,
+            },
+            StakeSourceMsg::Native { denom } => {
+                if denom.is_empty() {
+                    return Err(StdError::generic_err(
+                        "Native staking source denom must not be empty",
+                    ));
+                }
+                StakeSource::Native { denom }
+            }
+        };
+
+        if validated.iter().any(|s| s.source == stake_source) {
+            return Err(StdError::generic_err(format!(
+                "Duplicate staking source: {}",
+                stake_source
+            )));
+        }
+        if source.denom_multiplier == 0 {
+            return Err(StdError::generic_err(
+                "Staking source denom_multiplier must be greater than zero",
+            ));
+        }
+
+        validated.push(StakingSource {
+            source: stake_source,
+            weight: source.weight,
+            denom_multiplier: source.denom_multiplier,
+            max_staked_tokens: source.max_staked_tokens,
+        });
+    }
+
+    Ok(validated)
+}
+
+/// Converts the config of the first release
+/// Settings it didn't have default to its behavior: unweighted reviews
+/// without decay, eligibility requirements, pair limits, appeals or tiers
+pub fn migrate_legacy_config(legacy: LegacyConfig, vouching: VouchingParams) -> Config {
+    let params = legacy.trust_score_params;
+
+    Config {
+        admin: legacy.admin,
+        maintainer: Some(legacy.maintainer),
+        staking_sources: vec![StakingSource {
+            source: StakeSource::Cw20Stake {
+                contract: legacy.staking_contract,
+            },
+            weight: Decimal::one(),
+            denom_multiplier: params.denom_multiplier,
+            max_staked_tokens: legacy.max_staked_tokens,
+        }],
+        review_interval: legacy.review_interval,
+        max_staked_days: legacy.max_staked_days,
+        min_sync_interval: 86400,
+        max_rating: legacy.max_rating,
+        rating_half_life: 0,
+        score_model: ScoreModel::Linear(LinearModelParams {
+            base_score: params.base_score,
+            rating_multiplier: params.rating_multiplier,
+            stake_amount_denominator: params.stake_amount_denominator,
+            min_stake_days: params.min_stake_days,
+            rating_floor_denominator: params.rating_floor_denominator,
+            max_score: LEGACY_MAX_SCORE,
+        }),
+        review_weight: ReviewWeightParams {
+            reference_score: 0,
+            reference_order_value: Uint128::zero(),
+            min_weight: Decimal::one(),
+            max_weight: Decimal::one(),
+        },
+        reviewer_eligibility: ReviewerEligibility {
+            min_score: 0,
+            min_stake: Decimal::zero(),
+            min_account_age: 0,
+            min_order_value: Uint128::zero(),
+            ineligible_reviews: IneligibleReviewPolicy::Reject,
+        },
+        pair_review_limit: PairReviewLimit {
+            window: 0,
+            max_weight: Decimal::zero(),
+        },
+        appeal_window: 0,
+        vouching,
+        tiers: vec![],
+    }
+}
+
+/// Converts a trust account of the first release, staking in the legacy staking contract
+/// The rating becomes full-weight reviews, and the stake timer is backdated by the days staked
+pub fn migrate_legacy_trust_info(
+    legacy: LegacyTrustInfo,
+    config: &Config,
+    now: Timestamp,
+) -> TrustInfo {
+    let data = legacy.data;
+    let rating = data.rating * RATING_PRECISION;
+
+    let stakes = match (config.staking_sources.first(), data.stake_amount.is_zero()) {
+        (Some(source), false) => vec![SourceStake {
+            source: source.source.clone(),
+            amount: data.stake_amount,
+        }],
+        _ => vec![],
+    };
+    let stake_start = match stakes.is_empty() {
+        true => None,
+        false => Some(Timestamp::from_seconds(
+            now.seconds().saturating_sub(data.stake_days * 86400),
+        )),
+    };
+
+    let trust_data = TrustData {
+        stake_days: data.stake_days,
+        stake_start,
+        stakes,
+        rating,
+        decayed_rating: rating,
+        rating_weight: rating.unsigned_abs(),
+        decayed_rating_weight: rating.unsigned_abs(),
+        ..TrustData::new(now)
+    };
+
+    TrustInfo {
+        score: calculate_trust_score(trust_data.clone(), config.clone()),
+        data: trust_data,
+    }
+}
+
+/// Trust info of an account brought up to date with `now`,
+/// or that of an empty account created at `now` if it has none
+pub fn current_trust_info(
+    storage: &dyn Storage,
+    address: &Addr,
+    config: &Config,
+    now: Timestamp,
+) -> StdResult<TrustInfo> {
+    let data = trust_accounts()
+        .may_load(storage, address)?
+        .map_or_else(|| TrustData::new(now), |info| info.data);
+    let data = refresh_trust_data(storage, data, config, now)?;
+    let score = calculate_trust_score(data.clone(), config.clone());
+
+    Ok(TrustInfo { score, data })
+}
+
+/// Converts a pending review of the first release,
+/// which didn't report the order's value nor the peer's role
+pub fn migrate_legacy_pending_review(legacy: LegacyPendingReview) -> PendingReview {
+    PendingReview {
+        peer: legacy.peer,
+        reviewer: legacy.reviewer,
+        commerce_contract: legacy.commerce_contract,
+        order_id: legacy.order_id,
+        order_value: None,
+        peer_role: TradeRole::Unknown,
+        expires_at: legacy.expires_at,
+        commitment: None,
+    }
+}
+
+/// Brings the time-dependent parts of an account up to date:
+/// the decayed rating and the amount of days staked
+/// Attestations expired or issued by removed attesters are dropped
+pub fn refresh_trust_data(
+    storage: &dyn Storage,
+    trust_data: TrustData,
+    config: &Config,
+    now: Timestamp,
+) -> StdResult<TrustData> {
+    let elapsed = now
+        .seconds()
+        .saturating_sub(trust_data.rating_updated_at.seconds());
+
+    let stake_days = match trust_data.stake_start {
+        Some(stake_start) => now.seconds().saturating_sub(stake_start.seconds()) / 86400,
+        None => 0,
+    };
+
+    Ok(TrustData {
+        stake_days,
+        decayed_rating: decay_rating(trust_data.decayed_rating, elapsed, config.rating_half_life),
+        decayed_rating_weight: decay_rating(
+            trust_data.decayed_rating_weight as i64,
+            elapsed,
+            config.rating_half_life,
+        ) as u64,
+        buyer_rating: decay_role_rating(trust_data.buyer_rating, elapsed, config.rating_half_life),
+        seller_rating: decay_role_rating(
+            trust_data.seller_rating,
+            elapsed,
+            config.rating_half_life,
+        ),
+        rating_updated_at: now,
+        attestations: trust_data
+            .attestations
+            .into_iter()
+            .filter(|attestation| match attestation.expires_at {
+                Some(expires_at) => now < expires_at,
+                None => true,
+            })
+            .filter(|attestation| ATTESTERS.has(storage, &attestation.attester))
+            .collect(),
+        ban: trust_data.ban.filter(|ban| ban.is_active(now)),
+        ..trust_data
+    })
+}
+
+/// Errors if the address is currently banned
+pub fn ensure_not_banned(
+    storage: &dyn Storage,
+    address: &Addr,
+    now: Timestamp,
+) -> Result<(), ContractError> {
+    match BANS.may_load(storage, address)? {
+        Some(ban) if ban.is_active(now) => Err(ContractError::Banned {
+            address: address.to_string(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+// Verify that a ban is well-formed
+pub fn validate_ban(
+    reason: &str,
+    expires_at: Option<Timestamp>,
+    now: Timestamp,
+) -> Result<(), ContractError> {
+    if reason.is_empty() {
+        return Err(ContractError::InvalidReason {});
+    }
+
+    if reason.chars().count() > MAX_COMMENT_LENGTH {
+        return Err(ContractError::CommentTooLong {
+            max_length: MAX_COMMENT_LENGTH as u64,
+        });
+    }
+
+    if let Some(expires_at) = expires_at {
+        if expires_at <= now {
+            return Err(ContractError::InvalidExpiration {});
+        }
+    }
+
+    Ok(())
+}
+
+// Verify that an attestation is well-formed
+pub fn validate_attestation(
+    kind: &str,
+    uri: &Option<String>,
+    expires_at: Option<Timestamp>,
+    now: Timestamp,
+) -> Result<(), ContractError> {
+    if kind.is_empty() || kind.len() > MAX_ATTESTATION_KIND_LENGTH {
+        return Err(ContractError::InvalidAttestationKind {
+            max_length: MAX_ATTESTATION_KIND_LENGTH as u64,
+        });
+    }
+
+    if let Some(uri) = uri {
+        if uri.is_empty() || uri.len() > MAX_URI_LENGTH {
+            return Err(ContractError::InvalidUri {
+                max_length: MAX_URI_LENGTH as u64,
+            });
+        }
+    }
+
+    if let Some(expires_at) = expires_at {
+        if expires_at <= now {
+            return Err(ContractError::InvalidExpiration {});
+        }
+    }
+
+    Ok(())
+}
+
+pub fn calculate_review_weight(
+    reviewer_score: Decimal,
+    order_value: Option<Uint128>,
+    params: &ReviewWeightParams,
+) -> Decimal {
+    // Reviews of orders of unknown value can't be weighted by it
+    let order_value = match order_value {
+        Some(order_value) => order_value,
+        None => return params.min_weight,
+    };
+
+    // A reference of zero disables the corresponding factor
+    let score_factor = match params.reference_score {
+        0 => Decimal::one(),
+        reference_score => reviewer_score * Decimal::from_ratio(1u64, reference_score),
+    };
+
+    let value_factor = match params.reference_order_value.is_zero() {
+        true => Decimal::one(),
+        false => Decimal::from_ratio(order_value, params.reference_order_value),
+    };
+
+    let weight = score_factor * value_factor;
+
+    match weight {
+        weight if weight < params.min_weight => params.min_weight,
+        weight if weight > params.max_weight => params.max_weight,
+        weight => weight,
+    }
+}
+
+/// Caps a review's rating delta to the weight left for the pair in the current window
+/// Returns the capped delta alongside the updated pair counter
+pub fn apply_pair_review_limit(
+    counter: Option<PairReviewCounter>,
+    rating_delta: i64,
+    limit: &PairReviewLimit,
+    now: Timestamp,
+) -> (i64, PairReviewCounter) {
+    let counter = match counter {
+        Some(counter)
+            if limit.window == 0 || now < counter.window_start.plus_seconds(limit.window) =>
+        {
+            counter
+        }
+        // A new window starts with the first review after the last one ended
+        counter => PairReviewCounter {
+            window_start: now,
+            reviews: 0,
+            weight: 0,
+            total_reviews: counter.map_or(0, |counter| counter.total_reviews),
+        },
+    };
+
+    let rating_delta = match limit.window {
+        0 => rating_delta,
+        _ => {
+            let max_weight =
+                (Uint128::from(RATING_PRECISION as u128) * limit.max_weight).u128() as u64;
+            let remaining = max_weight.saturating_sub(counter.weight);
+            match rating_delta.unsigned_abs() > remaining {
+                true => rating_delta.signum() * remaining as i64,
+                false => rating_delta,
+            }
+        }
+    };
+
+    let counter = PairReviewCounter {
+        reviews: counter.reviews + 1,
+        weight: counter.weight + rating_delta.unsigned_abs(),
+        total_reviews: counter.total_reviews + 1,
+        ..counter
+    };
+
+    (rating_delta, counter)
+}
+
+/// First reviewer eligibility requirement that isn't met, if any
+pub fn unmet_reviewer_requirement(
+    reviewer: &TrustInfo,
+    order_value: Uint128,
+    config: &Config,
+    now: Timestamp,
+) -> Option<String> {
+    let rules = &config.reviewer_eligibility;
+    let account_age = now
+        .seconds()
+        .saturating_sub(reviewer.data.created_at.seconds());
+
+    if reviewer.score < Decimal::from_ratio(rules.min_score, 1u64) {
+        Some(format!("min_score: {}", rules.min_score))
+    } else if weighted_stake_amount(&reviewer.data, &config.staking_sources) < rules.min_stake {
+        Some(format!("min_stake: {}", rules.min_stake))
+    } else if account_age < rules.min_account_age {
+        Some(format!("min_account_age: {}", rules.min_account_age))
+    } else if order_value < rules.min_order_value {
+        Some(format!("min_order_value: {}", rules.min_order_value))
+    } else {
+        None
+    }
+}
+
+/// First voucher eligibility requirement that isn't met, if any
+pub fn unmet_voucher_requirement(
+    voucher: &TrustInfo,
+    config: &Config,
+    now: Timestamp,
+) -> Option<String> {
+    let rules = &config.vouching.voucher_eligibility;
+    let account_age = now
+        .seconds()
+        .saturating_sub(voucher.data.created_at.seconds());
+
+    if voucher.score < Decimal::from_ratio(rules.min_score, 1u64) {
+        Some(format!("min_score: {}", rules.min_score))
+    } else if weighted_stake_amount(&voucher.data, &config.staking_sources) < rules.min_stake {
+        Some(format!("min_stake: {}", rules.min_stake))
+    } else if account_age < rules.min_account_age {
+        Some(format!("min_account_age: {}", rules.min_account_age))
+    } else {
+        None
+    }
+}
+
+pub fn calculate_rating_delta(review: &ReviewResult, weight: Decimal) -> i64 {
+    let delta = (Uint128::from(RATING_PRECISION as u128) * weight).u128() as i64;
+
+    match review {
+        ReviewResult::ThumbsUp => delta,
+        ReviewResult::ThumbsDown => -delta,
+    }
+}
+
+/// Hex-encoded SHA-256 hash of `{peer}:{review}:{salt}`,
+/// committed to by a reviewer before revealing their review
+pub fn review_commitment(peer: &Addr, review: &ReviewResult, salt: &str) -> String {
+    let review = match review {
+        ReviewResult::ThumbsUp => "thumbs_up",
+        ReviewResult::ThumbsDown => "thumbs_down",
+    };
+
+    Sha256::digest(format!("{}:{}:{}", peer, review, salt))
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+// Verify that a review commitment looks like a SHA-256 hash
+pub fn validate_review_commitment(commitment: &str) -> Result<(), ContractError> {
+    if commitment.len() != 64 || !commitment.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ContractError::InvalidCommitment {});
+    }
+
+    Ok(())
+}
+
+// Verify that a review comment or reply is within limits
+pub fn validate_review_comment(comment: &ReviewComment) -> Result<(), ContractError> {
+    match comment {
+        ReviewComment::Text(text) => {
+            if text.is_empty() {
+                return Err(ContractError::EmptyComment {});
+            }
+
+            if text.chars().count() > MAX_COMMENT_LENGTH {
+                return Err(ContractError::CommentTooLong {
+                    max_length: MAX_COMMENT_LENGTH as u64,
+                });
+            }
+        }
+        ReviewComment::Anchored { hash, uri } => {
+            // SHA-256 hashes are 32 bytes, or 64 hex characters
+            if hash.len() != 64 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(ContractError::InvalidContentHash {});
+            }
+
+            if uri.is_empty() || uri.len() > MAX_URI_LENGTH {
+                return Err(ContractError::InvalidUri {
+                    max_length: MAX_URI_LENGTH as u64,
+                });
+            }
+        }
+    }
+
+    Ok(())
 }
@@ -1,24 +1,88 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Decimal, Timestamp, Uint128};
+use cw20::Cw20ReceiveMsg;
+use cw20_stake::hooks::StakeChangedHookMsg;
 
 use crate::{
     response::{
-        AccountsResponse, ConfigResponse, PendingReviewResponse, PendingReviewsResponse,
-        StakeAmountResponse, TrustInfoResponse,
+        AccountsResponse, AppealResponse, AppealsResponse, AttestersResponse, BanResponse,
+        BansResponse, CommerceCodeIdsResponse, CommerceContractsResponse, ConfigResponse,
+        PairReviewCounterResponse, PairReviewCountersResponse, PendingReviewResponse,
+        PendingReviewsResponse, RankResponse, ReviewResponse, ReviewsResponse,
+        ScoreBreakdownResponse, SimulateScoreResponse, StakeAmountResponse,
+        StoreScopedTrustResponse, TierResponse, TopAccountsResponse, TrustInfoAtHeightResponse,
+        TrustInfoResponse, UnbondingsResponse, VouchesResponse,
+    },
+    state::{
+        AppealDecision, AppealStatus, PairReviewLimit, ReviewComment, ReviewResult,
+        ReviewWeightParams, ReviewerEligibility, ScoreModel, Tier, TradeRole, TrustData,
+        VoucherEligibility,
     },
-    state::{ReviewResult, TrustScoreParams},
 };
 
 #[cw_serde]
 pub struct InstantiateMsg {
-    pub maintainer: String,
-    pub staking_contract: String,
-    pub commerce_code_id: u64,
+    pub maintainer: Option<String>,
+    pub staking_sources: Vec<StakingSourceMsg>,
+    pub commerce_code_ids: Vec<u64>,
     pub review_interval: u64,
-    pub max_staked_tokens: Uint128,
     pub max_staked_days: u64,
-    pub trust_score_params: TrustScoreParams,
+    pub min_sync_interval: u64,
+    pub score_model: ScoreModel,
+    pub review_weight: ReviewWeightParams,
+    pub reviewer_eligibility: ReviewerEligibility,
+    pub pair_review_limit: PairReviewLimit,
     pub max_rating: u64,
+    pub rating_half_life: u64,
+    pub appeal_window: u64,
+    pub vouching: VouchingParamsMsg,
+    pub tiers: Vec<Tier>,
+}
+
+#[cw_serde]
+pub struct StakingSourceMsg {
+    pub source: StakeSourceMsg,
+    /// How much a whole token staked in this source counts towards the stake
+    pub weight: Decimal,
+    /// Amount of base units per whole token
+    pub denom_multiplier: u128,
+    /// Max amount of tokens taken into consideration for the staking calculation
+    pub max_staked_tokens: Uint128,
+}
+
+#[cw_serde]
+pub struct VouchingParamsMsg {
+    /// Address of the CW20 token locked to vouch
+    pub token: String,
+    /// Amount of base units per whole token
+    pub denom_multiplier: u128,
+    /// Score added per whole token vouched by a voucher with `reference_score`
+    pub score_per_token: Decimal,
+    /// Voucher trust score at which a vouch carries its full weight
+    pub reference_score: u64,
+    /// Maximum score added by the vouches an account receives
+    pub max_bonus: u64,
+    /// Time in seconds before unvouched tokens can be claimed
+    pub unbonding_period: u64,
+    /// Share of the tokens vouched for an account, including unbonding ones,
+    /// taken when the admin slashes the account
+    pub slash_share: Decimal,
+    /// Requirements an account must meet to vouch for another
+    pub voucher_eligibility: VoucherEligibility,
+}
+
+#[cw_serde]
+pub enum ReceiveMsg {
+    /// Vouch locks the tokens sent to back an address' reputation
+    Vouch { address: String },
+}
+
+#[cw_serde]
+pub enum StakeSourceMsg {
+    /// Tokens staked in a CW20 staking contract
+    Cw20Stake { contract: String },
+    /// Tokens natively delegated to validators
+    Native { denom: String },
 }
 
 #[cw_serde]
@@ -29,43 +93,197 @@ pub enum ExecuteMsg {
         /// Address of the DAO governing the contract
         admin: String,
         /// Address of the client used for updating staking info
-        maintainer: String,
-        /// CW20 staking contract
-        staking_contract: String,
-        /// Commerce contract CodeID
-        commerce_code_id: u64,
+        maintainer: Option<String>,
+        /// Staking contracts taken into consideration for the staking calculation
+        staking_sources: Vec<StakingSourceMsg>,
         /// Interval between reviews in seconds
         review_interval: u64,
-        /// Max amount of tokens taken into consideration for the staking calculation
-        max_staked_tokens: Uint128,
         /// Max amount of days staked taken into consideration for the staking calc
         max_staked_days: u64,
+        /// Minimum time in seconds between two staking syncs of the same account
+        min_sync_interval: u64,
         /// Maximum rating score (prevents inflated scores)
         max_rating: u64,
+        /// Time in seconds after which a review counts half as much
+        rating_half_life: u64,
         /// How we calculate the trust score
         /// Should be adjusted based on token allocation/price/TVL
-        trust_score_params: TrustScoreParams,
+        /// Switching models recomputes scores as accounts are touched
+        score_model: ScoreModel,
+    },
+    /// UpdateReviewParams makes changes to how reviews are weighed and appealed
+    UpdateReviewParams {
+        /// How much a review moves the peer's rating
+        review_weight: ReviewWeightParams,
+        /// Requirements a reviewer must meet for their review to count
+        reviewer_eligibility: ReviewerEligibility,
+        /// How much rating a single reviewer can contribute to a peer over time
+        pair_review_limit: PairReviewLimit,
+        /// Time in seconds during which a peer can appeal a negative review
+        appeal_window: u64,
+    },
+    /// UpdateVouchingParams makes changes to how accounts can lock tokens to vouch for each other
+    /// The token can't change while any tokens are vouched or unbonding
+    UpdateVouchingParams { vouching: VouchingParamsMsg },
+    /// UpdateTiers replaces the named tiers, as score thresholds in ascending order
+    /// An account is in the highest tier whose `min_score` its score reaches
+    UpdateTiers { tiers: Vec<Tier> },
+    /// AddCommerceCodeId allows contracts instantiated from a code ID
+    /// to register pending reviews
+    AddCommerceCodeId { code_id: u64 },
+    /// RemoveCommerceCodeId stops accepting pending reviews from a code ID
+    /// Contracts added with `AddCommerceContract` are still accepted
+    RemoveCommerceCodeId { code_id: u64 },
+    /// AddCommerceContract allows a single contract to register pending reviews,
+    /// whatever its code ID
+    AddCommerceContract { address: String },
+    /// RemoveCommerceContract stops accepting pending reviews from a contract
+    /// added with `AddCommerceContract`
+    RemoveCommerceContract { address: String },
+    /// AddAttester approves an address to issue attestations, or updates an attester
+    /// `score_bonus` is added to the score of attested addresses, 0 for badges only
+    AddAttester {
+        address: String,
+        name: String,
+        score_bonus: u64,
+    },
+    /// RemoveAttester stops an address from issuing attestations
+    /// Attestations it already issued no longer count, and are dropped with the next update
+    RemoveAttester { address: String },
+    /// DecideAppeal upholds or strikes an appealed review
+    /// Upheld reviews count again in the peer's rating, struck ones stay reversed
+    DecideAppeal { id: u64, decision: AppealDecision },
+    /// Ban zeroes the trust score of an address proven fraudulent until `expires_at`,
+    /// or permanently. Banned addresses can neither review nor be reviewed.
+    /// The tokens vouched for them are slashed separately, with `Slash`.
+    Ban {
+        address: String,
+        reason: String,
+        expires_at: Option<Timestamp>,
+    },
+    /// Unban lifts a ban and removes it from the registry
+    Unban { address: String },
+    /// Slash takes `slash_share` of the tokens vouched for an address
+    /// found at fault, including unbonding ones, and sends them to the admin.
+    /// Vouchers are slashed `limit` at a time, in order, starting after `start_after`;
+    /// the last one slashed is returned in the `last_voucher` attribute.
+    Slash {
+        address: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
     },
 
-    /// --- PRIVILEGED ---
+    /// --- PERMISSIONLESS ---
     /// UpdateStakingInfo re-queries the staking information
     /// for a specific address and updates their trust score.
+    /// Can be called by anyone once every `min_sync_interval` per address,
+    /// or at any time by the maintainer.
     UpdateStakingInfo { address: String },
+    /// UpdateStakingInfoBatch syncs several addresses at once.
+    /// Each address is subject to the same rules as `UpdateStakingInfo`,
+    /// and failures are reported in events instead of aborting the batch.
+    UpdateStakingInfoBatch { addresses: Vec<String> },
+    /// UpdateStakingInfoNext syncs the next `limit` trust accounts,
+    /// walking all accounts in order and starting over once done.
+    /// Each sender walks the accounts with their own cursor.
+    UpdateStakingInfoNext { limit: u32 },
+    /// Register creates a trust account for the sender,
+    /// so their stake counts before they receive any review
+    Register {},
+
+    /// Unvouch unlocks tokens vouched for an address, all of them by default.
+    /// They can be claimed after `unbonding_period`, and can be slashed until then.
+    /// Unvouching while tokens are unbonding adds to them and restarts the period.
+    Unvouch {
+        address: String,
+        amount: Option<Uint128>,
+    },
+    /// ClaimUnvouched sends back unvouched tokens whose unbonding period ended
+    ClaimUnvouched {},
+    /// Receive handles CW20 tokens sent along a `ReceiveMsg`
+    Receive(Cw20ReceiveMsg),
+
+    /// --- PRIVILEGED ---
+    /// Attest issues an attestation of some kind against an address.
+    /// Can only be called by an approved attester.
+    /// Attesting the same kind again replaces the previous attestation.
+    Attest {
+        address: String,
+        kind: String,
+        uri: Option<String>,
+        expires_at: Option<Timestamp>,
+    },
+    /// RevokeAttestation removes an attestation issued by `attester`, the sender by default.
+    /// Attesters can revoke their own attestations, the admin can revoke any.
+    RevokeAttestation {
+        address: String,
+        kind: String,
+        attester: Option<String>,
+    },
+    /// StakeChangeHook is called by a staking source whenever
+    /// an address stakes or unstakes tokens, and updates their trust score.
+    /// The trust contract must be registered as a hook on each staking contract.
+    StakeChangeHook(StakeChangedHookMsg),
     /// RegisterPendingReview adds a pending review for a user.
-    /// Can only be called by a commerce contract (checked by codeID).
+    /// Can only be called by an approved commerce contract,
+    /// either by code ID or by address.
+    /// `order_value` is used to weight the review, which carries `min_weight` without it,
+    /// and `peer_role` decides which of the peer's role ratings it counts towards, none without it.
     RegisterPendingReview {
         peer: String,
         reviewer: String,
         order_id: u64,
+        order_value: Option<Uint128>,
+        peer_role: Option<TradeRole>,
     },
 
     /// --- USER-FACING ---
     /// Review allows a user to leave a thumbs-up/down review
     /// to another user if there is a pending review.
+    /// An optional comment can be attached to the review.
+    /// In a mutual review, the counterpart must have committed to theirs first,
+    /// and the review is held back until they reveal theirs or their reveal window ends.
     Review {
         address: String,
         review: ReviewResult,
+        comment: Option<ReviewComment>,
     },
+    /// CommitReview commits to a review without revealing it,
+    /// so the counterpart of a mutual review can't retaliate.
+    /// `commitment` is the hex-encoded SHA-256 hash of `{address}:{review}:{salt}`,
+    /// where `review` is either `thumbs_up` or `thumbs_down`.
+    CommitReview { address: String, commitment: String },
+    /// RevealReview reveals and applies a committed review.
+    /// Allowed once the counterpart has committed too, or once the review window ends.
+    /// Reviews left unrevealed for `review_interval` after that count as no review.
+    /// In a mutual review, the revealed review is held back until the counterpart
+    /// reveals theirs, then both are applied together.
+    RevealReview {
+        address: String,
+        review: ReviewResult,
+        salt: String,
+        comment: Option<ReviewComment>,
+    },
+    /// AppealReview allows a reviewed user to appeal a negative review
+    /// within `appeal_window` seconds, for the admin to decide on.
+    /// The review doesn't count in their rating until the appeal is decided.
+    AppealReview {
+        id: u64,
+        reason: Option<ReviewComment>,
+    },
+    /// ReplyToReview allows a reviewed user to publicly reply
+    /// to a review they received. Only one reply is allowed per review.
+    ReplyToReview { id: u64, reply: ReviewComment },
+    /// SettleReview applies a review held back for the counterpart of a mutual review
+    /// once they can no longer reveal theirs. Anyone can settle a held review.
+    SettleReview { address: String },
+}
+
+#[cw_serde]
+pub struct MigrateMsg {
+    /// Vouching settings, required when migrating from the first release,
+    /// whose other new settings default to its former behavior
+    pub vouching: Option<VouchingParamsMsg>,
 }
 
 #[cw_serde]
@@ -75,21 +293,118 @@ pub enum QueryMsg {
     /// Query the contract's config.
     #[returns(ConfigResponse)]
     Config {},
+    /// Get the code IDs of approved commerce contracts.
+    #[returns(CommerceCodeIdsResponse)]
+    CommerceCodeIds {},
+    /// Get the commerce contracts approved by address.
+    #[returns(CommerceContractsResponse)]
+    CommerceContracts {},
+    /// Get all approved attesters.
+    #[returns(AttestersResponse)]
+    Attesters {},
+    /// Get the ban of an address, and whether it is still active.
+    #[returns(BanResponse)]
+    Ban { address: String },
+    /// Get the ban registry, expired bans included until lifted.
+    #[returns(BansResponse)]
+    Bans {
+        limit: Option<u32>,
+        start_after: Option<String>,
+    },
 
     /// --- USER-FACING ---
     /// Get an address' trust info.
     #[returns(TrustInfoResponse)]
     TrustInfo { address: String },
-    /// Get an address' staking info.
+    /// Get the tier an address' score falls in.
+    /// Addresses without a trust account are scored as an empty account.
+    #[returns(TierResponse)]
+    Tier { address: String },
+    /// Get an address' rating and score as reviewed through a single commerce contract,
+    /// apart from its protocol-wide reputation.
+    #[returns(StoreScopedTrustResponse)]
+    StoreScopedTrust {
+        address: String,
+        commerce_contract: String,
+    },
+    /// Get an address' trust info as it was at the start of a past block.
+    #[returns(TrustInfoAtHeightResponse)]
+    TrustInfoAtHeight { address: String, height: u64 },
+    /// Get the components making up an address' trust score.
+    #[returns(ScoreBreakdownResponse)]
+    ScoreBreakdown { address: String },
+    /// Calculate the trust score of hypothetical trust data with the current score model.
+    #[returns(SimulateScoreResponse)]
+    SimulateScore { data: Box<TrustData> },
+    /// Get an address' staking info, broken down by staking source.
     #[returns(StakeAmountResponse)]
     StakeAmount { address: String },
     /// Get all trust accounts.
     #[returns(AccountsResponse)]
     Accounts {},
+    /// Get trust accounts by descending score, as of their last update.
+    #[returns(TopAccountsResponse)]
+    TopAccounts {
+        limit: Option<u32>,
+        start_after: Option<String>,
+    },
+    /// Get an address' position in `TopAccounts`.
+    /// Only the top 1000 positions are told apart, lower ones are reported as capped.
+    #[returns(RankResponse)]
+    Rank { address: String },
     /// Get pending review by peer
     #[returns(PendingReviewResponse)]
     PendingReview { peer: String },
     /// Get all pending reviews by reviewer
     #[returns(PendingReviewsResponse)]
     PendingReviewsByReviewer { reviewer: String },
+    /// Get a review by ID
+    #[returns(ReviewResponse)]
+    Review { id: u64 },
+    /// Get reviews received by an address, by review ID
+    #[returns(ReviewsResponse)]
+    ReviewsByPeer {
+        peer: String,
+        limit: Option<u32>,
+        start_after: Option<u64>,
+    },
+    /// Get reviews left by an address, by review ID
+    #[returns(ReviewsResponse)]
+    ReviewsByReviewer {
+        reviewer: String,
+        limit: Option<u32>,
+        start_after: Option<u64>,
+    },
+    /// Get all vouches given by an address
+    #[returns(VouchesResponse)]
+    VouchesGiven { voucher: String },
+    /// Get all vouches received by an address
+    #[returns(VouchesResponse)]
+    VouchesReceived { vouchee: String },
+    /// Get an address' unvouched tokens waiting for their unbonding period to end
+    #[returns(UnbondingsResponse)]
+    Unbondings { voucher: String },
+    /// Get the appeal of a review, by review ID
+    #[returns(AppealResponse)]
+    Appeal { id: u64 },
+    /// Get all appeals filed by an address
+    #[returns(AppealsResponse)]
+    AppealsByPeer { peer: String },
+    /// Get appeals by review ID, optionally filtered by status
+    #[returns(AppealsResponse)]
+    Appeals {
+        status: Option<AppealStatus>,
+        limit: Option<u32>,
+        start_after: Option<u64>,
+    },
+    /// Get the reviews a reviewer left a peer, as of the last one.
+    #[returns(PairReviewCounterResponse)]
+    PairReviewCounter { peer: String, reviewer: String },
+    /// Get the reviews each reviewer left a peer, as of their last one.
+    #[returns(PairReviewCountersResponse)]
+    PairReviewCounters {
+        peer: String,
+        limit: Option<u32>,
+        start_after: Option<String>,
+    },
 }
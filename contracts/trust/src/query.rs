@@ -1,12 +1,28 @@
-use cosmwasm_std::{to_binary, Order, QueryRequest, StdError, WasmQuery};
+use cosmwasm_std::{Addr, Decimal, Env, Order, Uint128};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::{Deps, StdResult};
 
 use cw20_stake::msg::StakedValueResponse;
+use cw_storage_plus::Bound;
+
+// Settings for pagination
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+/// Maximum amount of accounts counted to rank an address, bounding the cost of `Rank`
+const MAX_RANKED: usize = 1_000;
 
 use crate::{
+    helpers::{
+        calculate_role_score, calculate_score_breakdown, calculate_store_score,
+        calculate_trust_score, refresh_store_rating, refresh_trust_data, tier_for_score,
+        weighted_source_amount, ScoreBreakdown,
+    },
     response::*,
-    state::{pending_reviews, CONFIG, TRUST_INFO},
+    state::{
+        appeals, pending_reviews, reviews, trust_accounts, unbondings, vouches, AppealStatus,
+        StakeSource, StakingSource, TradeRole, TrustData, TrustInfo, ATTESTERS, BANS,
+        COMMERCE_CODE_IDS, COMMERCE_CONTRACTS, CONFIG, PAIR_REVIEWS, STORE_RATINGS,
+    },
 };
 
 pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
@@ -15,11 +31,147 @@ pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
     Ok(ConfigResponse { config })
 }
 
-pub fn query_trust_info(deps: Deps, address: String) -> StdResult<TrustInfoResponse> {
+pub fn query_commerce_code_ids(deps: Deps) -> StdResult<CommerceCodeIdsResponse> {
+    let code_ids = COMMERCE_CODE_IDS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(CommerceCodeIdsResponse { code_ids })
+}
+
+pub fn query_commerce_contracts(deps: Deps) -> StdResult<CommerceContractsResponse> {
+    let contracts = COMMERCE_CONTRACTS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(CommerceContractsResponse { contracts })
+}
+
+pub fn query_trust_info(deps: Deps, env: Env, address: String) -> StdResult<TrustInfoResponse> {
     let address = deps.api.addr_validate(&address)?;
-    let trust_info = TRUST_INFO.may_load(deps.storage, &address)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    // Time-dependent data and the score are brought up to date with the current block time
+    let trust_info = trust_accounts()
+        .may_load(deps.storage, &address)?
+        .map(|trust_info| -> StdResult<TrustInfo> {
+            let data = refresh_trust_data(deps.storage, trust_info.data, &config, env.block.time)?;
+            let score = calculate_trust_score(data.clone(), config.clone());
+
+            Ok(TrustInfo { score, data })
+        })
+        .transpose()?;
+
+    let stale = match trust_info.as_ref().and_then(|info| info.data.last_synced) {
+        Some(last_synced) => env.block.time >= last_synced.plus_seconds(config.min_sync_interval),
+        None => true,
+    };
+
+    let tier = trust_info
+        .as_ref()
+        .and_then(|info| tier_for_score(info.score, &config.tiers));
+
+    let role_scores = trust_info.as_ref().map(|info| RoleScores {
+        buyer: calculate_role_score(&info.data, &TradeRole::Buyer, &config),
+        seller: calculate_role_score(&info.data, &TradeRole::Seller, &config),
+    });
+
+    Ok(TrustInfoResponse {
+        trust_info,
+        stale,
+        tier,
+        role_scores,
+    })
+}
+
+pub fn query_store_scoped_trust(
+    deps: Deps,
+    env: Env,
+    address: String,
+    commerce_contract: String,
+) -> StdResult<StoreScopedTrustResponse> {
+    let api = deps.api;
+    let address = api.addr_validate(&address)?;
+    let commerce_contract = api.addr_validate(&commerce_contract)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    let store_rating = STORE_RATINGS
+        .may_load(deps.storage, (&address, &commerce_contract))?
+        .map(|store_rating| refresh_store_rating(store_rating, &config, env.block.time));
+
+    // Stake and other components of the score stay protocol-wide
+    let score = match (
+        &store_rating,
+        trust_accounts().may_load(deps.storage, &address)?,
+    ) {
+        (Some(store_rating), Some(trust_info)) => {
+            let data = refresh_trust_data(deps.storage, trust_info.data, &config, env.block.time)?;
+            Some(calculate_store_score(&data, store_rating, &config))
+        }
+        _ => None,
+    };
+
+    Ok(StoreScopedTrustResponse {
+        store_rating,
+        score,
+    })
+}
+
+pub fn query_tier(deps: Deps, env: Env, address: String) -> StdResult<TierResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    // Addresses without a trust account are scored as an empty account
+    let data = trust_accounts()
+        .may_load(deps.storage, &address)?
+        .map_or_else(|| TrustData::new(env.block.time), |info| info.data);
+    let data = refresh_trust_data(deps.storage, data, &config, env.block.time)?;
+    let score = calculate_trust_score(data, config.clone());
+
+    Ok(TierResponse {
+        tier: tier_for_score(score, &config.tiers),
+        score,
+    })
+}
+
+pub fn query_trust_info_at_height(
+    deps: Deps,
+    address: String,
+    height: u64,
+) -> StdResult<TrustInfoAtHeightResponse> {
+    let address = deps.api.addr_validate(&address)?;
+
+    let trust_info = trust_accounts().may_load_at_height(deps.storage, &address, height)?;
+
+    Ok(TrustInfoAtHeightResponse { trust_info, height })
+}
+
+pub fn query_score_breakdown(
+    deps: Deps,
+    env: Env,
+    address: String,
+) -> StdResult<ScoreBreakdownResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let config = CONFIG.load(deps.storage)?;
 
-    Ok(TrustInfoResponse { trust_info })
+    let breakdown = trust_accounts()
+        .may_load(deps.storage, &address)?
+        .map(|trust_info| -> StdResult<ScoreBreakdown> {
+            let data = refresh_trust_data(deps.storage, trust_info.data, &config, env.block.time)?;
+            Ok(calculate_score_breakdown(&data, &config))
+        })
+        .transpose()?;
+
+    Ok(ScoreBreakdownResponse { breakdown })
+}
+
+pub fn query_simulate_score(deps: Deps, data: TrustData) -> StdResult<SimulateScoreResponse> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // The data is taken as is, without bringing it up to date
+    let breakdown = calculate_score_breakdown(&data, &config);
+
+    Ok(SimulateScoreResponse { breakdown })
 }
 
 pub fn query_stake_amount(deps: Deps, address: String) -> StdResult<StakeAmountResponse> {
@@ -27,31 +179,123 @@ pub fn query_stake_amount(deps: Deps, address: String) -> StdResult<StakeAmountR
 
     let config = CONFIG.load(deps.storage)?;
 
-    let query_msg = cw20_stake::msg::QueryMsg::StakedValue {
-        address: address.to_string(),
-    };
-    let res: Result<StakedValueResponse, StdError> =
-        deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
-            contract_addr: config.staking_contract.to_string(),
-            msg: to_binary(&query_msg)?,
-        }));
-
-    let stake_amount = match res {
-        Ok(stake_amount) => Some(stake_amount.value),
-        Err(_) => None,
-    };
+    let sources = config
+        .staking_sources
+        .iter()
+        .map(|source| {
+            let stake_amount = query_source_stake(deps, source, &address);
+
+            SourceStakeAmount {
+                source: source.source.clone(),
+                stake_amount,
+                weighted_amount: weighted_source_amount(source, stake_amount.unwrap_or_default()),
+            }
+        })
+        .collect::<Vec<_>>();
 
-    Ok(StakeAmountResponse { stake_amount })
+    let stake_amount = sources.iter().fold(Decimal::zero(), |total, source| {
+        total + source.weighted_amount
+    });
+
+    Ok(StakeAmountResponse {
+        stake_amount,
+        sources,
+    })
+}
+
+/// Amount of tokens an address has staked in a staking source
+/// None if the source could not be queried
+pub fn query_source_stake(deps: Deps, source: &StakingSource, address: &Addr) -> Option<Uint128> {
+    match &source.source {
+        StakeSource::Cw20Stake { contract } => {
+            let query_msg = cw20_stake::msg::QueryMsg::StakedValue {
+                address: address.to_string(),
+            };
+            let res: StdResult<StakedValueResponse> =
+                deps.querier.query_wasm_smart(contract, &query_msg);
+
+            res.ok().map(|res| res.value)
+        }
+        StakeSource::Native { denom } => {
+            let delegations = deps.querier.query_all_delegations(address).ok()?;
+
+            Some(
+                delegations
+                    .iter()
+                    .filter(|delegation| &delegation.amount.denom == denom)
+                    .map(|delegation| delegation.amount.amount)
+                    .sum(),
+            )
+        }
+    }
 }
 
 pub fn query_accounts(deps: Deps) -> StdResult<AccountsResponse> {
-    let accounts = TRUST_INFO
+    let accounts = trust_accounts()
         .keys(deps.storage, None, None, Order::Ascending)
         .collect::<StdResult<Vec<_>>>()?;
 
     Ok(AccountsResponse { accounts })
 }
 
+pub fn query_top_accounts(
+    deps: Deps,
+    limit: Option<u32>,
+    start_after: Option<String>,
+) -> StdResult<TopAccountsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    // Accounts are ranked by score, then by address
+    let max = match start_after {
+        Some(start_after) => {
+            let start_after = deps.api.addr_validate(&start_after)?;
+            let trust_info = trust_accounts().load(deps.storage, &start_after)?;
+            Some(Bound::exclusive((
+                trust_info.score.atomics().u128(),
+                start_after,
+            )))
+        }
+        None => None,
+    };
+
+    let accounts = trust_accounts()
+        .idx
+        .by_score
+        .range(deps.storage, None, max, Order::Descending)
+        .take(limit)
+        .map(|res| {
+            res.map(|(address, trust_info)| AccountScore {
+                address,
+                score: trust_info.score,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(TopAccountsResponse { accounts })
+}
+
+pub fn query_rank(deps: Deps, address: String) -> StdResult<RankResponse> {
+    let address = deps.api.addr_validate(&address)?;
+
+    let (rank, capped) = match trust_accounts().may_load(deps.storage, &address)? {
+        Some(trust_info) => {
+            // Amount of accounts ranked above the address, counting no more than `MAX_RANKED`
+            let min = Bound::exclusive((trust_info.score.atomics().u128(), address));
+            let above = trust_accounts()
+                .idx
+                .by_score
+                .keys_raw(deps.storage, Some(min), None, Order::Ascending)
+                .take(MAX_RANKED)
+                .count();
+
+            (Some(above as u64 + 1), above == MAX_RANKED)
+        }
+        None => (None, false),
+    };
+
+    Ok(RankResponse { rank, capped })
+}
+
 pub fn query_pending_review(deps: Deps, peer: String) -> StdResult<PendingReviewResponse> {
     let api = deps.api;
     let peer = api.addr_validate(&peer)?;
@@ -78,3 +322,236 @@ pub fn query_pending_reviews_by_reviewer(
 
     Ok(PendingReviewsResponse { pending_reviews })
 }
+
+pub fn query_review(deps: Deps, id: u64) -> StdResult<ReviewResponse> {
+    let review = reviews().may_load(deps.storage, id)?;
+
+    Ok(ReviewResponse { review })
+}
+
+pub fn query_reviews_by_peer(
+    deps: Deps,
+    peer: String,
+    limit: Option<u32>,
+    start_after: Option<u64>,
+) -> StdResult<ReviewsResponse> {
+    let api = deps.api;
+    let peer = api.addr_validate(&peer)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let reviews = reviews()
+        .idx
+        .by_peer
+        .prefix(peer)
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|res| res.map(|item| item.1))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(ReviewsResponse { reviews })
+}
+
+pub fn query_reviews_by_reviewer(
+    deps: Deps,
+    reviewer: String,
+    limit: Option<u32>,
+    start_after: Option<u64>,
+) -> StdResult<ReviewsResponse> {
+    let api = deps.api;
+    let reviewer = api.addr_validate(&reviewer)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let reviews = reviews()
+        .idx
+        .by_reviewer
+        .prefix(reviewer)
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|res| res.map(|item| item.1))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(ReviewsResponse { reviews })
+}
+
+pub fn query_pair_review_counter(
+    deps: Deps,
+    peer: String,
+    reviewer: String,
+) -> StdResult<PairReviewCounterResponse> {
+    let api = deps.api;
+    let peer = api.addr_validate(&peer)?;
+    let reviewer = api.addr_validate(&reviewer)?;
+
+    let counter = PAIR_REVIEWS.may_load(deps.storage, (&peer, &reviewer))?;
+
+    Ok(PairReviewCounterResponse { counter })
+}
+
+pub fn query_pair_review_counters(
+    deps: Deps,
+    peer: String,
+    limit: Option<u32>,
+    start_after: Option<String>,
+) -> StdResult<PairReviewCountersResponse> {
+    let api = deps.api;
+    let peer = api.addr_validate(&peer)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = start_after
+        .map(|start_after| api.addr_validate(&start_after))
+        .transpose()?;
+
+    let counters = PAIR_REVIEWS
+        .prefix(&peer)
+        .range(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|res| res.map(|(reviewer, counter)| ReviewerCounter { reviewer, counter }))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(PairReviewCountersResponse { counters })
+}
+
+pub fn query_appeal(deps: Deps, id: u64) -> StdResult<AppealResponse> {
+    let appeal = appeals().may_load(deps.storage, id)?;
+
+    Ok(AppealResponse { appeal })
+}
+
+pub fn query_appeals_by_peer(deps: Deps, peer: String) -> StdResult<AppealsResponse> {
+    let api = deps.api;
+    let peer = api.addr_validate(&peer)?;
+
+    let appeals = appeals()
+        .idx
+        .by_peer
+        .prefix(peer)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|res| res.map(|item| item.1))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(AppealsResponse { appeals })
+}
+
+pub fn query_appeals(
+    deps: Deps,
+    status: Option<AppealStatus>,
+    limit: Option<u32>,
+    start_after: Option<u64>,
+) -> StdResult<AppealsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let appeals = appeals()
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .map(|res| res.map(|item| item.1))
+        .filter(|res| match (res, &status) {
+            (Ok(appeal), Some(status)) => appeal.status == *status,
+            _ => true,
+        })
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(AppealsResponse { appeals })
+}
+
+pub fn query_attesters(deps: Deps) -> StdResult<AttestersResponse> {
+    let attesters = ATTESTERS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|res| res.map(|(address, attester)| AttesterInfo { address, attester }))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(AttestersResponse { attesters })
+}
+
+pub fn query_ban(deps: Deps, env: Env, address: String) -> StdResult<BanResponse> {
+    let address = deps.api.addr_validate(&address)?;
+
+    let ban = BANS.may_load(deps.storage, &address)?;
+    let active = matches!(&ban, Some(ban) if ban.is_active(env.block.time));
+
+    Ok(BanResponse { ban, active })
+}
+
+pub fn query_bans(
+    deps: Deps,
+    limit: Option<u32>,
+    start_after: Option<String>,
+) -> StdResult<BansResponse> {
+    let api = deps.api;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = start_after
+        .map(|start_after| api.addr_validate(&start_after))
+        .transpose()?;
+
+    let bans = BANS
+        .range(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|res| res.map(|(address, ban)| BanInfo { address, ban }))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(BansResponse { bans })
+}
+
+pub fn query_vouches_given(deps: Deps, voucher: String) -> StdResult<VouchesResponse> {
+    let api = deps.api;
+    let voucher = api.addr_validate(&voucher)?;
+
+    let vouches = vouches()
+        .prefix(&voucher)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|res| res.map(|item| item.1))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(VouchesResponse { vouches })
+}
+
+pub fn query_vouches_received(deps: Deps, vouchee: String) -> StdResult<VouchesResponse> {
+    let api = deps.api;
+    let vouchee = api.addr_validate(&vouchee)?;
+
+    let vouches = vouches()
+        .idx
+        .by_vouchee
+        .prefix(vouchee)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|res| res.map(|item| item.1))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(VouchesResponse { vouches })
+}
+
+pub fn query_unbondings(deps: Deps, voucher: String) -> StdResult<UnbondingsResponse> {
+    let api = deps.api;
+    let voucher = api.addr_validate(&voucher)?;
+
+    let unbondings = unbondings()
+        .prefix(&voucher)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|res| res.map(|item| item.1))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(UnbondingsResponse { unbondings })
+}
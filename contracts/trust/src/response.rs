@@ -1,20 +1,86 @@
-use crate::state::{Config, PendingReview, TrustInfo};
+use crate::helpers::ScoreBreakdown;
+use crate::state::{
+    Appeal, Attester, Ban, Config, PairReviewCounter, PendingReview, Review, StakeSource,
+    StoreRating, Tier, TrustInfo, Unbonding, Vouch,
+};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Decimal, Uint128};
 
 #[cw_serde]
 pub struct ConfigResponse {
     pub config: Config,
 }
 
+#[cw_serde]
+pub struct CommerceCodeIdsResponse {
+    pub code_ids: Vec<u64>,
+}
+
+#[cw_serde]
+pub struct CommerceContractsResponse {
+    pub contracts: Vec<Addr>,
+}
+
 #[cw_serde]
 pub struct TrustInfoResponse {
+    /// Trust info as of the current block
+    /// `data.rating` is the raw rating, `data.decayed_rating` the time-decayed one
+    pub trust_info: Option<TrustInfo>,
+    /// Whether the staking info is due for a resync
+    /// True if it was never synced or last synced more than `min_sync_interval` ago
+    pub stale: bool,
+    /// Tier the score falls in, None if below the lowest tier or without a trust account
+    pub tier: Option<Tier>,
+    /// Scores counting only the reviews received in each role
+    pub role_scores: Option<RoleScores>,
+}
+
+#[cw_serde]
+pub struct RoleScores {
+    pub buyer: Decimal,
+    pub seller: Decimal,
+}
+
+#[cw_serde]
+pub struct StoreScopedTrustResponse {
+    /// Rating received through the commerce contract, as of the current block
+    /// None if the address was never reviewed through it
+    pub store_rating: Option<StoreRating>,
+    /// Trust score counting only the rating received through the commerce contract
+    /// None without a store rating or a trust account
+    pub score: Option<Decimal>,
+}
+
+#[cw_serde]
+pub struct TierResponse {
+    /// None if the score is below the lowest tier
+    pub tier: Option<Tier>,
+    pub score: Decimal,
+}
+
+#[cw_serde]
+pub struct TrustInfoAtHeightResponse {
+    /// Trust info as stored at the start of block `height`
+    /// Unlike `TrustInfo`, it is not brought up to date with the block time
     pub trust_info: Option<TrustInfo>,
+    pub height: u64,
 }
 
 #[cw_serde]
 pub struct StakeAmountResponse {
+    /// Amount of whole tokens staked across all staking sources,
+    /// capped and weighted as in the trust score calculation
+    pub stake_amount: Decimal,
+    pub sources: Vec<SourceStakeAmount>,
+}
+
+#[cw_serde]
+pub struct SourceStakeAmount {
+    pub source: StakeSource,
+    /// Amount of tokens staked, None if the source could not be queried
     pub stake_amount: Option<Uint128>,
+    /// Amount of whole tokens staked, capped and weighted
+    pub weighted_amount: Decimal,
 }
 
 #[cw_serde]
@@ -22,6 +88,27 @@ pub struct AccountsResponse {
     pub accounts: Vec<Addr>,
 }
 
+#[cw_serde]
+pub struct AccountScore {
+    pub address: Addr,
+    /// Trust score as of the account's last update
+    pub score: Decimal,
+}
+
+#[cw_serde]
+pub struct TopAccountsResponse {
+    pub accounts: Vec<AccountScore>,
+}
+
+#[cw_serde]
+pub struct RankResponse {
+    /// Position of the address in the leaderboard, starting at 1
+    /// None if the address has no trust account
+    pub rank: Option<u64>,
+    /// Only so many accounts are counted, if set the address ranks `rank` or lower
+    pub capped: bool,
+}
+
 #[cw_serde]
 pub struct PendingReviewResponse {
     pub pending_review: Option<PendingReview>,
@@ -31,3 +118,92 @@ pub struct PendingReviewResponse {
 pub struct PendingReviewsResponse {
     pub pending_reviews: Vec<PendingReview>,
 }
+
+#[cw_serde]
+pub struct ReviewResponse {
+    pub review: Option<Review>,
+}
+
+#[cw_serde]
+pub struct ReviewsResponse {
+    pub reviews: Vec<Review>,
+}
+
+#[cw_serde]
+pub struct ScoreBreakdownResponse {
+    /// Components of the trust score as of the current block
+    /// None if the address has no trust account
+    pub breakdown: Option<ScoreBreakdown>,
+}
+
+#[cw_serde]
+pub struct SimulateScoreResponse {
+    pub breakdown: ScoreBreakdown,
+}
+
+#[cw_serde]
+pub struct PairReviewCounterResponse {
+    /// As of the reviewer's last review, the window may have ended since
+    pub counter: Option<PairReviewCounter>,
+}
+
+#[cw_serde]
+pub struct ReviewerCounter {
+    pub reviewer: Addr,
+    pub counter: PairReviewCounter,
+}
+
+#[cw_serde]
+pub struct PairReviewCountersResponse {
+    /// Ordered by reviewer address
+    pub counters: Vec<ReviewerCounter>,
+}
+
+#[cw_serde]
+pub struct AppealResponse {
+    pub appeal: Option<Appeal>,
+}
+
+#[cw_serde]
+pub struct AppealsResponse {
+    pub appeals: Vec<Appeal>,
+}
+
+#[cw_serde]
+pub struct AttesterInfo {
+    pub address: Addr,
+    pub attester: Attester,
+}
+
+#[cw_serde]
+pub struct AttestersResponse {
+    pub attesters: Vec<AttesterInfo>,
+}
+
+#[cw_serde]
+pub struct BanResponse {
+    pub ban: Option<Ban>,
+    /// False if there is no ban, or if it expired
+    pub active: bool,
+}
+
+#[cw_serde]
+pub struct BanInfo {
+    pub address: Addr,
+    pub ban: Ban,
+}
+
+#[cw_serde]
+pub struct BansResponse {
+    pub bans: Vec<BanInfo>,
+}
+
+#[cw_serde]
+pub struct VouchesResponse {
+    pub vouches: Vec<Vouch>,
+}
+
+#[cw_serde]
+pub struct UnbondingsResponse {
+    pub unbondings: Vec<Unbonding>,
+}
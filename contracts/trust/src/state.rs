@@ -1,7 +1,11 @@
+use std::fmt;
+
 use cosmwasm_schema::cw_serde;
 
-use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
-use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex, UniqueIndex};
+use cosmwasm_std::{Addr, Decimal, Empty, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::{
+    Index, IndexList, IndexedMap, IndexedSnapshotMap, Item, Map, MultiIndex, Strategy, UniqueIndex,
+};
 
 #[cw_serde]
 pub struct PendingReview {
@@ -9,7 +13,26 @@ pub struct PendingReview {
     pub reviewer: Addr,
     pub commerce_contract: Addr,
     pub order_id: u64,
+    /// Value of the order, as reported by the commerce contract
+    /// Commerce contracts predating order values don't report it
+    pub order_value: Option<Uint128>,
+    /// Side of the order the peer was on
+    pub peer_role: TradeRole,
     pub expires_at: Timestamp,
+    /// Hex-encoded SHA-256 hash of the review, committed to before revealing it
+    /// See `helpers::review_commitment`
+    pub commitment: Option<String>,
+}
+
+impl PendingReview {
+    /// Time until which the pending review can be acted upon
+    /// Committed reviews can be revealed for another `review_interval` after they expire
+    pub fn deadline(&self, review_interval: u64) -> Timestamp {
+        match self.commitment {
+            Some(_) => self.expires_at.plus_seconds(review_interval),
+            None => self.expires_at,
+        }
+    }
 }
 
 pub struct PendingReviewIndexes<'a> {
@@ -40,6 +63,17 @@ pub fn pending_reviews<'a>() -> IndexedMap<'a, Addr, PendingReview, PendingRevie
     IndexedMap::new("pending_reviews", indexes)
 }
 
+/// Review left in a mutual review while the counterpart could still reveal theirs
+/// Held back until they do, so that they can't decide whether to reveal based on it
+#[cw_serde]
+pub struct HeldReview {
+    pub result: ReviewResult,
+    pub comment: Option<ReviewComment>,
+}
+
+// Held reviews, by the address of the reviewed peer
+pub const HELD_REVIEWS: Map<&Addr, HeldReview> = Map::new("held_reviews");
+
 #[cw_serde]
 pub struct TrustInfo {
     pub score: Decimal,
@@ -48,26 +82,231 @@ pub struct TrustInfo {
 
 #[cw_serde]
 pub struct TrustData {
-    /// Amount of days the user has been staking k tokens
-    /// The timer resets if any tokens are undelegated
+    /// Amount of days the user has been staking k tokens, as of the last update
+    /// Derived from `stake_start` and the block time
     pub stake_days: u64,
-    /// Amount of tokens currently staked
-    pub stake_amount: Uint128,
-    /// Amount of tokens staked, as queried in the previous run
-    pub prev_stake_amount: Uint128,
+    /// Time at which the user started staking
+    /// The timer resets if any tokens are undelegated from any source
+    pub stake_start: Option<Timestamp>,
+    /// Amount of tokens currently staked in each staking source, as last observed
+    /// Sources with nothing staked are left out
+    pub stakes: Vec<SourceStake>,
+    /// Last time the staking info was synced with the staking sources
+    pub last_synced: Option<Timestamp>,
     /// Rating score, as defined by thumbs-up/down reviews and dispute decisions
     /// Thumbs up +1, Thumbs down -1, Win dispute +0, Lose dispute -5
+    /// Stored in units of `1 / RATING_PRECISION`, as reviews are weighted
+    pub rating: i64,
+    /// Rating where older reviews count less, as of `rating_updated_at`
+    /// Halves every `Config::rating_half_life` seconds
+    pub decayed_rating: i64,
+    /// Total weight of the reviews making up the rating
+    /// Stored in units of `1 / RATING_PRECISION`, like the rating
+    pub rating_weight: u64,
+    /// Total weight of the reviews making up the decayed rating
+    pub decayed_rating_weight: u64,
+    /// Last time the decayed rating was brought up to date
+    pub rating_updated_at: Timestamp,
+    /// Ratings earned as a buyer, also counted in the combined rating
+    pub buyer_rating: RoleRating,
+    /// Ratings earned as a seller, also counted in the combined rating
+    pub seller_rating: RoleRating,
+    /// Time at which the trust account was created
+    pub created_at: Timestamp,
+    /// Attestations issued by approved attesters
+    /// Expired attestations are dropped whenever the account is updated
+    pub attestations: Vec<Attestation>,
+    /// Amount of whole tokens vouched for the account,
+    /// each vouch weighted by the voucher's score when vouching
+    pub vouched_amount: Decimal,
+    /// Ban issued by the admin, zeroing the trust score while active
+    /// Expired bans are dropped whenever the account is updated
+    pub ban: Option<Ban>,
+}
+
+impl TrustData {
+    /// An empty trust account, as of `now`
+    pub fn new(now: Timestamp) -> Self {
+        TrustData {
+            stake_days: 0,
+            stake_start: None,
+            stakes: vec![],
+            last_synced: None,
+            rating: 0,
+            decayed_rating: 0,
+            rating_weight: 0,
+            decayed_rating_weight: 0,
+            rating_updated_at: now,
+            buyer_rating: RoleRating::default(),
+            seller_rating: RoleRating::default(),
+            created_at: now,
+            attestations: vec![],
+            vouched_amount: Decimal::zero(),
+            ban: None,
+        }
+    }
+
+    /// Rating earned in a role, if the role is known
+    pub fn role_rating(&self, role: &TradeRole) -> Option<&RoleRating> {
+        match role {
+            TradeRole::Buyer => Some(&self.buyer_rating),
+            TradeRole::Seller => Some(&self.seller_rating),
+            TradeRole::Unknown => None,
+        }
+    }
+
+    /// Amount of tokens staked in a staking source, as last observed
+    pub fn stake_amount(&self, source: &StakeSource) -> Uint128 {
+        self.stakes
+            .iter()
+            .find(|stake| stake.source == *source)
+            .map_or(Uint128::zero(), |stake| stake.amount)
+    }
+}
+
+/// Rating earned in a single role, kept like the combined rating of `TrustData`
+#[cw_serde]
+#[derive(Default)]
+pub struct RoleRating {
     pub rating: i64,
+    pub decayed_rating: i64,
+    pub rating_weight: u64,
+    pub decayed_rating_weight: u64,
 }
 
+/// Maximum length of an attestation kind
+pub const MAX_ATTESTATION_KIND_LENGTH: usize = 64;
+
 #[cw_serde]
-pub struct TrustScoreParams {
+pub struct Attestation {
+    pub attester: Addr,
+    /// Type of attestation, e.g. `kyc` or `business_registry`
+    pub kind: String,
+    /// Optional pointer to the evidence backing the attestation
+    pub uri: Option<String>,
+    /// Score added by the attestation, as approved for the attester when issued
+    pub score_bonus: u64,
+    pub issued_at: Timestamp,
+    pub expires_at: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct SourceStake {
+    /// Where the tokens are staked
+    pub source: StakeSource,
+    pub amount: Uint128,
+}
+
+/// Rating units per full-weight thumbs-up/down review
+pub const RATING_PRECISION: i64 = 1_000;
+
+/// How the trust score is calculated from an account's rating and stake
+/// `R` is the rating, `T` the weighted amount of tokens staked and `D` the days staked
+/// Scores are recomputed with the current model whenever an account is touched
+#[cw_serde]
+pub enum ScoreModel {
+    /// t = base + m * R + ( T / d ) * D - min_stake_days * ( R / f )
+    Linear(LinearModelParams),
+    /// t = base + m * R + s * log2(1 + T * D / d)
+    /// Large stakes are worth less and less, so they can't drown out the rating
+    LogStake(LogStakeModelParams),
+    /// t = base + r * (2 * p - 1) + ( T / d ) * D
+    /// `p` is the share of positive reviews, averaged with `prior_weight` reviews
+    /// of share `prior_share` so a handful of reviews can't swing the score
+    BayesianRating(BayesianRatingModelParams),
+}
+
+#[cw_serde]
+pub struct LinearModelParams {
     pub base_score: u64,
     pub rating_multiplier: u64,
     pub stake_amount_denominator: u64,
     pub min_stake_days: u64,
     pub rating_floor_denominator: u64,
-    pub denom_multiplier: u128,
+    pub max_score: u64,
+}
+
+#[cw_serde]
+pub struct LogStakeModelParams {
+    pub base_score: u64,
+    pub rating_multiplier: u64,
+    /// Score added each time `T * D / stake_amount_denominator` doubles
+    pub stake_multiplier: u64,
+    pub stake_amount_denominator: u64,
+    pub max_score: u64,
+}
+
+#[cw_serde]
+pub struct BayesianRatingModelParams {
+    pub base_score: u64,
+    /// Score added by an all-positive rating, or removed by an all-negative one
+    pub rating_range: u64,
+    /// Amount of full-weight reviews the prior counts as
+    pub prior_weight: u64,
+    /// Share of positive reviews assumed before any review is received
+    pub prior_share: Decimal,
+    pub stake_amount_denominator: u64,
+    pub max_score: u64,
+}
+
+/// A review's weight is `(reviewer score / reference_score) * (order value / reference_order_value)`,
+/// clamped between `min_weight` and `max_weight`.
+/// Reviews of orders whose value isn't known carry `min_weight`.
+#[cw_serde]
+pub struct ReviewWeightParams {
+    /// Reviewer trust score at which a review carries its full weight
+    pub reference_score: u64,
+    /// Order value at which a review carries its full weight
+    pub reference_order_value: Uint128,
+    /// Minimum weight of a review
+    pub min_weight: Decimal,
+    /// Maximum weight of a review
+    pub max_weight: Decimal,
+}
+
+/// Requirements a reviewer must meet for their review to count
+/// Reviewers without a trust account are treated as an empty account created at review time
+#[cw_serde]
+pub struct ReviewerEligibility {
+    /// Minimum trust score of the reviewer
+    pub min_score: u64,
+    /// Minimum weighted amount of tokens staked by the reviewer, as last observed
+    pub min_stake: Decimal,
+    /// Minimum time in seconds since the reviewer's trust account was created
+    pub min_account_age: u64,
+    /// Minimum value of the reviewed order, as reported by the commerce contract
+    /// Orders of unknown value count as worth nothing
+    pub min_order_value: Uint128,
+    /// What happens to reviews left by ineligible reviewers
+    pub ineligible_reviews: IneligibleReviewPolicy,
+}
+
+/// How much rating a single reviewer can contribute to a peer over time
+#[cw_serde]
+pub struct PairReviewLimit {
+    /// Length of a window in seconds, set to 0 to disable the limit
+    pub window: u64,
+    /// Maximum total weight of the reviews a reviewer can leave a peer within a window
+    /// Reviews past the cap are recorded with whatever weight is left
+    pub max_weight: Decimal,
+}
+
+#[cw_serde]
+pub enum IneligibleReviewPolicy {
+    /// The review is rejected and the pending review is kept
+    Reject,
+    /// The review is recorded but doesn't move the peer's rating
+    ZeroWeight,
+}
+
+/// Side of an order an account was on
+#[cw_serde]
+pub enum TradeRole {
+    Buyer,
+    Seller,
+    /// Not reported by commerce contracts predating roles
+    /// Reviews in an unknown role only count towards the combined rating
+    Unknown,
 }
 
 #[cw_serde]
@@ -76,29 +315,521 @@ pub enum ReviewResult {
     ThumbsDown,
 }
 
-pub const TRUST_INFO: Map<&Addr, TrustInfo> = Map::new("trust_info");
+/// Maximum length of a comment stored on-chain
+pub const MAX_COMMENT_LENGTH: usize = 280;
+/// Maximum length of a URI pointing to an off-chain comment
+pub const MAX_URI_LENGTH: usize = 256;
+
+#[cw_serde]
+pub enum ReviewComment {
+    /// Short comment stored on-chain
+    Text(String),
+    /// Comment stored off-chain (e.g. on IPFS)
+    /// `hash` is the hex-encoded SHA-256 hash of the content found at `uri`
+    Anchored { hash: String, uri: String },
+}
+
+#[cw_serde]
+pub struct Review {
+    pub id: u64,
+    pub peer: Addr,
+    pub reviewer: Addr,
+    pub commerce_contract: Addr,
+    pub order_id: u64,
+    /// Side of the order the peer was on
+    pub peer_role: TradeRole,
+    pub result: ReviewResult,
+    /// Weighted change applied to the peer's rating
+    pub rating_delta: i64,
+    pub comment: Option<ReviewComment>,
+    /// Public reply from the reviewed peer, can only be set once
+    pub reply: Option<ReviewComment>,
+    pub created_at: Timestamp,
+}
+
+// Incrementing ID counter
+pub const REVIEW_ID_COUNTER: Item<u64> = Item::new("review_id_counter");
+
+// Get next incrementing ID
+pub fn next_review_id(store: &mut dyn Storage) -> StdResult<u64> {
+    let id: u64 = REVIEW_ID_COUNTER.may_load(store)?.unwrap_or_default() + 1;
+    REVIEW_ID_COUNTER.save(store, &id)?;
+
+    Ok(id)
+}
+
+pub struct ReviewIndexes<'a> {
+    pub by_peer: MultiIndex<'a, Addr, Review, u64>,
+    pub by_reviewer: MultiIndex<'a, Addr, Review, u64>,
+}
+
+impl<'a> IndexList<Review> for ReviewIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Review>> + '_> {
+        let v: Vec<&dyn Index<Review>> = vec![&self.by_peer, &self.by_reviewer];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn reviews<'a>() -> IndexedMap<'a, u64, Review, ReviewIndexes<'a>> {
+    let indexes = ReviewIndexes {
+        by_peer: MultiIndex::new(|_, d: &Review| d.peer.clone(), "reviews", "reviews__peer"),
+        by_reviewer: MultiIndex::new(
+            |_, d: &Review| d.reviewer.clone(),
+            "reviews",
+            "reviews__reviewer",
+        ),
+    };
+
+    IndexedMap::new("reviews", indexes)
+}
+
+#[cw_serde]
+pub enum AppealStatus {
+    /// Awaiting a decision from the admin, the review doesn't count meanwhile
+    Pending,
+    /// The review stands, and counts again
+    Upheld,
+    /// The review was reversed for good
+    Struck,
+}
+
+#[cw_serde]
+pub enum AppealDecision {
+    Uphold,
+    Strike,
+}
+
+/// Appeal of a negative review by the reviewed peer, keyed by review ID
+#[cw_serde]
+pub struct Appeal {
+    pub review_id: u64,
+    pub peer: Addr,
+    /// Rating delta of the review when it was appealed
+    /// Removed from the peer's rating while pending, and restored if the review is upheld
+    pub rating_delta: i64,
+    pub reason: Option<ReviewComment>,
+    pub status: AppealStatus,
+    pub created_at: Timestamp,
+    pub decided_at: Option<Timestamp>,
+}
+
+pub struct AppealIndexes<'a> {
+    pub by_peer: MultiIndex<'a, Addr, Appeal, u64>,
+}
+
+impl<'a> IndexList<Appeal> for AppealIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Appeal>> + '_> {
+        let v: Vec<&dyn Index<Appeal>> = vec![&self.by_peer];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn appeals<'a>() -> IndexedMap<'a, u64, Appeal, AppealIndexes<'a>> {
+    let indexes = AppealIndexes {
+        by_peer: MultiIndex::new(|_, d: &Appeal| d.peer.clone(), "appeals", "appeals__peer"),
+    };
+
+    IndexedMap::new("appeals", indexes)
+}
+
+pub struct TrustInfoIndexes<'a> {
+    pub by_score: MultiIndex<'a, u128, TrustInfo, Addr>,
+}
+
+impl<'a> IndexList<TrustInfo> for TrustInfoIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<TrustInfo>> + '_> {
+        let v: Vec<&dyn Index<TrustInfo>> = vec![&self.by_score];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Trust accounts, indexed by score as of their last update
+/// Every change is recorded so trust info can be looked up at past heights
+pub fn trust_accounts<'a>() -> IndexedSnapshotMap<'a, &'a Addr, TrustInfo, TrustInfoIndexes<'a>> {
+    let indexes = TrustInfoIndexes {
+        by_score: MultiIndex::new(
+            |_, d: &TrustInfo| d.score.atomics().u128(),
+            "trust_info",
+            "trust_info__score",
+        ),
+    };
+
+    IndexedSnapshotMap::new(
+        "trust_info",
+        "trust_info__checkpoints",
+        "trust_info__changelog",
+        Strategy::EveryBlock,
+        indexes,
+    )
+}
+
+/// Code IDs of the commerce contracts allowed to register pending reviews
+pub const COMMERCE_CODE_IDS: Map<u64, Empty> = Map::new("commerce_code_ids");
+
+/// Commerce contracts allowed to register pending reviews, whatever their code ID
+pub const COMMERCE_CONTRACTS: Map<&Addr, Empty> = Map::new("commerce_contracts");
+
+#[cw_serde]
+pub struct PairReviewCounter {
+    /// Start of the current window
+    pub window_start: Timestamp,
+    /// Amount of reviews left within the current window
+    pub reviews: u64,
+    /// Total weight of the reviews left within the current window
+    /// Stored in units of `1 / RATING_PRECISION`, like the rating
+    pub weight: u64,
+    /// Amount of reviews left since the first one
+    pub total_reviews: u64,
+}
+
+/// Reviews left by a reviewer to a peer, keyed by (peer, reviewer)
+pub const PAIR_REVIEWS: Map<(&Addr, &Addr), PairReviewCounter> = Map::new("pair_reviews");
+
+/// Rating an address received through a single commerce contract,
+/// kept like the combined rating of `TrustData`
+#[cw_serde]
+pub struct StoreRating {
+    pub rating: i64,
+    pub decayed_rating: i64,
+    pub rating_weight: u64,
+    pub decayed_rating_weight: u64,
+    /// Amount of reviews received through the commerce contract
+    pub reviews: u64,
+    pub rating_updated_at: Timestamp,
+}
+
+impl StoreRating {
+    /// An empty store rating, as of `now`
+    pub fn new(now: Timestamp) -> Self {
+        StoreRating {
+            rating: 0,
+            decayed_rating: 0,
+            rating_weight: 0,
+            decayed_rating_weight: 0,
+            reviews: 0,
+            rating_updated_at: now,
+        }
+    }
+}
+
+/// Rating received by an address through a commerce contract,
+/// keyed by (address, commerce contract)
+pub const STORE_RATINGS: Map<(&Addr, &Addr), StoreRating> = Map::new("store_ratings");
+
+#[cw_serde]
+pub struct Attester {
+    /// Name of the attester, e.g. a KYC provider
+    pub name: String,
+    /// Score added by each attestation issued, 0 for badges only
+    /// Several attestations from the same attester only count once
+    pub score_bonus: u64,
+}
+
+/// Attesters approved by the admin to issue attestations
+pub const ATTESTERS: Map<&Addr, Attester> = Map::new("attesters");
+
+/// Ban of an address proven fraudulent
+#[cw_serde]
+pub struct Ban {
+    pub reason: String,
+    pub banned_at: Timestamp,
+    /// None if the ban is permanent
+    pub expires_at: Option<Timestamp>,
+}
+
+impl Ban {
+    pub fn is_active(&self, now: Timestamp) -> bool {
+        match self.expires_at {
+            Some(expires_at) => now < expires_at,
+            None => true,
+        }
+    }
+}
+
+/// Registry of the bans issued by the admin, expired ones included until lifted
+pub const BANS: Map<&Addr, Ban> = Map::new("bans");
+
+/// Tokens locked by a voucher to back a vouchee's reputation
+#[cw_serde]
+pub struct Vouch {
+    pub voucher: Addr,
+    pub vouchee: Addr,
+    /// Amount of tokens locked, in base units
+    pub amount: Uint128,
+    /// Amount of whole tokens, weighted by the voucher's score when vouching
+    pub weighted_amount: Decimal,
+    pub created_at: Timestamp,
+}
+
+pub struct VouchIndexes<'a> {
+    pub by_vouchee: MultiIndex<'a, Addr, Vouch, (Addr, Addr)>,
+}
+
+impl<'a> IndexList<Vouch> for VouchIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Vouch>> + '_> {
+        let v: Vec<&dyn Index<Vouch>> = vec![&self.by_vouchee];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Vouches keyed by (voucher, vouchee)
+pub fn vouches<'a>() -> IndexedMap<'a, (&'a Addr, &'a Addr), Vouch, VouchIndexes<'a>> {
+    let indexes = VouchIndexes {
+        by_vouchee: MultiIndex::new(
+            |_, d: &Vouch| d.vouchee.clone(),
+            "vouches",
+            "vouches__vouchee",
+        ),
+    };
+
+    IndexedMap::new("vouches", indexes)
+}
+
+/// Unvouched tokens waiting for the unbonding period to end
+/// They can still be slashed until then
+#[cw_serde]
+pub struct Unbonding {
+    pub voucher: Addr,
+    pub vouchee: Addr,
+    pub amount: Uint128,
+    pub release_at: Timestamp,
+}
+
+pub struct UnbondingIndexes<'a> {
+    pub by_vouchee: MultiIndex<'a, Addr, Unbonding, (Addr, Addr)>,
+}
+
+impl<'a> IndexList<Unbonding> for UnbondingIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Unbonding>> + '_> {
+        let v: Vec<&dyn Index<Unbonding>> = vec![&self.by_vouchee];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Unbondings keyed by (voucher, vouchee)
+/// Unvouching again adds to the pending unbonding, so there is at most one per pair
+pub fn unbondings<'a>() -> IndexedMap<'a, (&'a Addr, &'a Addr), Unbonding, UnbondingIndexes<'a>> {
+    let indexes = UnbondingIndexes {
+        by_vouchee: MultiIndex::new(
+            |_, d: &Unbonding| d.vouchee.clone(),
+            "unbondings",
+            "unbondings__vouchee",
+        ),
+    };
+
+    IndexedMap::new("unbondings", indexes)
+}
+
+/// Last account synced by `UpdateStakingInfoNext`, per sender,
+/// so callers walking the accounts do not skip each other's accounts
+pub const SYNC_CURSORS: Map<&Addr, Addr> = Map::new("sync_cursors");
+
+/// Named range of trust scores, e.g. `Bronze`, for integrators to gate on
+#[cw_serde]
+pub struct Tier {
+    pub name: String,
+    /// Lowest score in the tier, up to the next tier's `min_score`
+    pub min_score: u64,
+}
 
 #[cw_serde]
 pub struct Config {
     /// Address of the DAO governing the contract
     pub admin: Addr,
     /// Address of the client used for updating staking info
-    pub maintainer: Addr,
-    /// CW20 staking contract
-    pub staking_contract: Addr,
-    /// Commerce contract CodeID
-    pub commerce_code_id: u64,
+    /// The maintainer is not subject to `min_sync_interval`
+    pub maintainer: Option<Addr>,
+    /// Staking contracts taken into consideration for the staking calculation
+    pub staking_sources: Vec<StakingSource>,
     /// Interval between reviews in seconds
     pub review_interval: u64,
-    /// Max amount of tokens taken into consideration for the staking calculation
-    pub max_staked_tokens: Uint128,
     /// Max amount of days staked taken into consideration for the staking calc
     pub max_staked_days: u64,
     /// Maximum rating score (prevents inflated scores)
     pub max_rating: u64,
+    /// Minimum time in seconds between two staking syncs of the same account
+    /// Does not apply to the maintainer
+    pub min_sync_interval: u64,
+    /// Time in seconds after which a review counts half as much
+    /// Set to 0 to disable rating decay
+    pub rating_half_life: u64,
     /// How we calculate the trust score
     /// Should be adjusted based on token allocation/price/TVL
-    pub trust_score_params: TrustScoreParams,
+    pub score_model: ScoreModel,
+    /// How much a review moves the peer's rating
+    pub review_weight: ReviewWeightParams,
+    /// Requirements a reviewer must meet for their review to count
+    pub reviewer_eligibility: ReviewerEligibility,
+    /// How much rating a single reviewer can contribute to a peer over time
+    pub pair_review_limit: PairReviewLimit,
+    /// Time in seconds during which a peer can appeal a negative review
+    pub appeal_window: u64,
+    /// How accounts can lock tokens to vouch for each other
+    pub vouching: VouchingParams,
+    /// Named tiers, as score thresholds in ascending order
+    /// An account is in the highest tier whose `min_score` its score reaches
+    pub tiers: Vec<Tier>,
+}
+
+#[cw_serde]
+pub struct VouchingParams {
+    /// CW20 token locked to vouch
+    pub token: Addr,
+    /// Amount of base units per whole token
+    pub denom_multiplier: u128,
+    /// Score added per whole token vouched by a voucher with `reference_score`
+    pub score_per_token: Decimal,
+    /// Voucher trust score at which a vouch carries its full weight
+    pub reference_score: u64,
+    /// Maximum score added by the vouches an account receives
+    pub max_bonus: u64,
+    /// Time in seconds before unvouched tokens can be claimed
+    pub unbonding_period: u64,
+    /// Share of the tokens vouched for an account, including unbonding ones,
+    /// taken when the admin slashes the account
+    pub slash_share: Decimal,
+    /// Requirements an account must meet to vouch for another
+    pub voucher_eligibility: VoucherEligibility,
+}
+
+/// Requirements a voucher must meet, checked against their current trust score
+/// Vouchers without a trust account are treated as an empty account created at vouch time
+#[cw_serde]
+pub struct VoucherEligibility {
+    /// Minimum trust score of the voucher
+    pub min_score: u64,
+    /// Minimum weighted amount of tokens staked by the voucher, as last observed
+    pub min_stake: Decimal,
+    /// Minimum time in seconds since the voucher's trust account was created
+    pub min_account_age: u64,
+}
+
+#[cw_serde]
+pub struct StakingSource {
+    pub source: StakeSource,
+    /// How much a whole token staked in this source counts towards the stake
+    pub weight: Decimal,
+    /// Amount of base units per whole token
+    pub denom_multiplier: u128,
+    /// Max amount of tokens taken into consideration for the staking calculation
+    pub max_staked_tokens: Uint128,
+}
+
+#[cw_serde]
+pub enum StakeSource {
+    /// Tokens staked in a CW20 staking contract
+    Cw20Stake { contract: Addr },
+    /// Tokens natively delegated to validators
+    Native { denom: String },
+}
+
+impl fmt::Display for StakeSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StakeSource::Cw20Stake { contract } => write!(f, "{}", contract),
+            StakeSource::Native { denom } => write!(f, "native:{}", denom),
+        }
+    }
+}
+
+impl fmt::Display for TradeRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TradeRole::Buyer => write!(f, "buyer"),
+            TradeRole::Seller => write!(f, "seller"),
+            TradeRole::Unknown => write!(f, "unknown"),
+        }
+    }
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
+
+/// --- LEGACY ---
+/// Shapes stored by the first release, only read when migrating from it
+
+#[cw_serde]
+pub struct LegacyTrustInfo {
+    pub score: Decimal,
+    pub data: LegacyTrustData,
+}
+
+#[cw_serde]
+pub struct LegacyTrustData {
+    /// Amount of days the user has been staking k tokens
+    pub stake_days: u64,
+    /// Amount of tokens currently staked
+    pub stake_amount: Uint128,
+    /// Amount of tokens staked, as queried in the previous run
+    pub prev_stake_amount: Uint128,
+    /// Rating score, in whole reviews
+    pub rating: i64,
+}
+
+#[cw_serde]
+pub struct LegacyTrustScoreParams {
+    pub base_score: u64,
+    pub rating_multiplier: u64,
+    pub stake_amount_denominator: u64,
+    pub min_stake_days: u64,
+    pub rating_floor_denominator: u64,
+    pub denom_multiplier: u128,
+}
+
+#[cw_serde]
+pub struct LegacyConfig {
+    pub admin: Addr,
+    pub maintainer: Addr,
+    pub staking_contract: Addr,
+    pub commerce_code_id: u64,
+    pub review_interval: u64,
+    pub max_staked_tokens: Uint128,
+    pub max_staked_days: u64,
+    pub max_rating: u64,
+    pub trust_score_params: LegacyTrustScoreParams,
+}
+
+#[cw_serde]
+pub struct LegacyPendingReview {
+    pub peer: Addr,
+    pub reviewer: Addr,
+    pub commerce_contract: Addr,
+    pub order_id: u64,
+    pub expires_at: Timestamp,
+}
+
+pub struct LegacyPendingReviewIndexes<'a> {
+    pub by_peer: UniqueIndex<'a, Addr, LegacyPendingReview>,
+    pub by_reviewer: MultiIndex<'a, Addr, LegacyPendingReview, Addr>,
+}
+
+impl<'a> IndexList<LegacyPendingReview> for LegacyPendingReviewIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<LegacyPendingReview>> + '_> {
+        let v: Vec<&dyn Index<LegacyPendingReview>> = vec![&self.by_peer, &self.by_reviewer];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Same namespaces and indexes as `pending_reviews`, whose unique index keeps a copy of each entry
+pub fn legacy_pending_reviews<'a>(
+) -> IndexedMap<'a, Addr, LegacyPendingReview, LegacyPendingReviewIndexes<'a>> {
+    let indexes = LegacyPendingReviewIndexes {
+        by_reviewer: MultiIndex::new(
+            |_, d: &LegacyPendingReview| d.peer.clone(),
+            "pending_reviews",
+            "pending_reviews__reviewer",
+        ),
+        by_peer: UniqueIndex::new(
+            |d: &LegacyPendingReview| d.reviewer.clone(),
+            "pending_reviews__peer",
+        ),
+    };
+
+    IndexedMap::new("pending_reviews", indexes)
+}
+
+/// Maximum score of the first release, which wasn't configurable
+pub const LEGACY_MAX_SCORE: u64 = 1500;
+
+pub const LEGACY_TRUST_INFO: Map<&Addr, LegacyTrustInfo> = Map::new("trust_info");
+
+pub const LEGACY_CONFIG: Item<LegacyConfig> = Item::new("config");
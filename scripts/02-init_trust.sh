@@ -1,20 +1,63 @@
 MSG=$(cat <<EOF
 {
   "maintainer": "$ADMIN",
-  "staking_contract": "$CW20_STAKE",
-  "commerce_code_id": $COMMERCE_CODE_ID,
+  "staking_sources": [
+    {
+      "source": { "cw20_stake": { "contract": "$CW20_STAKE" } },
+      "weight": "1",
+      "denom_multiplier": 1000000,
+      "max_staked_tokens": "5000000000"
+    }
+  ],
+  "commerce_code_ids": [$COMMERCE_CODE_ID],
   "review_interval": 86400,
-  "max_staked_tokens": "5000000000",
   "max_staked_days": 240,
-  "trust_score_params": {
-    "base_score": 500,
-    "rating_multiplier": 25,
-    "stake_amount_denominator": 2500,
-    "min_stake_days": 14,
-    "rating_floor_denominator": 10,
-    "denom_multiplier": "1000000"
+  "min_sync_interval": 86400,
+  "score_model": {
+    "linear": {
+      "base_score": 500,
+      "rating_multiplier": 25,
+      "stake_amount_denominator": 2500,
+      "min_stake_days": 14,
+      "rating_floor_denominator": 10,
+      "max_score": 1500
+    }
   },
-  "max_rating": 50
+  "review_weight": {
+    "reference_score": 500,
+    "reference_order_value": "100000000",
+    "min_weight": "0.1",
+    "max_weight": "2"
+  },
+  "reviewer_eligibility": {
+    "min_score": 400,
+    "min_stake": "0",
+    "min_account_age": 604800,
+    "min_order_value": "1000000",
+    "ineligible_reviews": "zero_weight"
+  },
+  "pair_review_limit": {
+    "window": 2592000,
+    "max_weight": "3"
+  },
+  "appeal_window": 1209600,
+  "vouching": {
+    "token": "$CW20",
+    "denom_multiplier": 1000000,
+    "score_per_token": "0.01",
+    "reference_score": 500,
+    "max_bonus": 250,
+    "unbonding_period": 1209600,
+    "slash_share": "0.5"
+  },
+  "tiers": [
+    { "name": "New", "min_score": 0 },
+    { "name": "Bronze", "min_score": 550 },
+    { "name": "Silver", "min_score": 750 },
+    { "name": "Gold", "min_score": 1000 }
+  ],
+  "max_rating": 50,
+  "rating_half_life": 15552000
 }
 EOF
 )
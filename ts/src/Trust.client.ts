@@ -6,21 +6,77 @@
 
 import { CosmWasmClient, SigningCosmWasmClient, ExecuteResult } from "@cosmjs/cosmwasm-stargate";
 import { Coin, StdFee } from "@cosmjs/amino";
-import { Uint128, InstantiateMsg, TrustScoreParams, ExecuteMsg, ReviewResult, QueryMsg, Addr, AccountsResponse, ConfigResponse, Config, Timestamp, Uint64, PendingReviewResponse, PendingReview, PendingReviewsResponse, StakeAmountResponse, Decimal, TrustInfoResponse, TrustInfo, TrustData } from "./Trust.types";
+import { Decimal, Uint128, IneligibleReviewPolicy, ScoreModel, StakeSourceMsg, InstantiateMsg, PairReviewLimit, ReviewWeightParams, ReviewerEligibility, LinearModelParams, LogStakeModelParams, BayesianRatingModelParams, StakingSourceMsg, Tier, VouchingParamsMsg, VoucherEligibility, ExecuteMsg, AppealDecision, Timestamp, Uint64, Binary, StakeChangedHookMsg, Addr, TradeRole, ReviewComment, ReviewResult, Cw20ReceiveMsg, QueryMsg, StakeSource, AppealStatus, TrustData, Attestation, Ban, RoleRating, SourceStake, MigrateMsg, AccountsResponse, AppealResponse, Appeal, AppealsResponse, AttestersResponse, AttesterInfo, Attester, BanResponse, BansResponse, BanInfo, CommerceCodeIdsResponse, CommerceContractsResponse, ConfigResponse, Config, StakingSource, VouchingParams, PairReviewCounterResponse, PairReviewCounter, PairReviewCountersResponse, ReviewerCounter, PendingReviewResponse, PendingReview, PendingReviewsResponse, RankResponse, ReviewResponse, Review, ReviewsResponse, ScoreBreakdownResponse, ScoreBreakdown, ScoreComponent, SimulateScoreResponse, StakeAmountResponse, SourceStakeAmount, StoreScopedTrustResponse, StoreRating, TierResponse, TopAccountsResponse, AccountScore, TrustInfoResponse, RoleScores, TrustInfo, TrustInfoAtHeightResponse, UnbondingsResponse, Unbonding, VouchesResponse, Vouch } from "./Trust.types";
 export interface TrustReadOnlyInterface {
   contractAddress: string;
   config: () => Promise<ConfigResponse>;
+  commerceCodeIds: () => Promise<CommerceCodeIdsResponse>;
+  commerceContracts: () => Promise<CommerceContractsResponse>;
+  attesters: () => Promise<AttestersResponse>;
+  ban: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<BanResponse>;
+  bans: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<BansResponse>;
   trustInfo: ({
     address
   }: {
     address: string;
   }) => Promise<TrustInfoResponse>;
+  tier: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<TierResponse>;
+  storeScopedTrust: ({
+    address,
+    commerceContract
+  }: {
+    address: string;
+    commerceContract: string;
+  }) => Promise<StoreScopedTrustResponse>;
+  trustInfoAtHeight: ({
+    address,
+    height
+  }: {
+    address: string;
+    height: number;
+  }) => Promise<TrustInfoAtHeightResponse>;
+  scoreBreakdown: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<ScoreBreakdownResponse>;
+  simulateScore: ({
+    data
+  }: {
+    data: TrustData;
+  }) => Promise<SimulateScoreResponse>;
   stakeAmount: ({
     address
   }: {
     address: string;
   }) => Promise<StakeAmountResponse>;
   accounts: () => Promise<AccountsResponse>;
+  topAccounts: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<TopAccountsResponse>;
+  rank: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<RankResponse>;
   pendingReview: ({
     peer
   }: {
@@ -31,6 +87,79 @@ export interface TrustReadOnlyInterface {
   }: {
     reviewer: string;
   }) => Promise<PendingReviewsResponse>;
+  review: ({
+    id
+  }: {
+    id: number;
+  }) => Promise<ReviewResponse>;
+  reviewsByPeer: ({
+    limit,
+    peer,
+    startAfter
+  }: {
+    limit?: number;
+    peer: string;
+    startAfter?: number;
+  }) => Promise<ReviewsResponse>;
+  reviewsByReviewer: ({
+    limit,
+    reviewer,
+    startAfter
+  }: {
+    limit?: number;
+    reviewer: string;
+    startAfter?: number;
+  }) => Promise<ReviewsResponse>;
+  vouchesGiven: ({
+    voucher
+  }: {
+    voucher: string;
+  }) => Promise<VouchesResponse>;
+  vouchesReceived: ({
+    vouchee
+  }: {
+    vouchee: string;
+  }) => Promise<VouchesResponse>;
+  unbondings: ({
+    voucher
+  }: {
+    voucher: string;
+  }) => Promise<UnbondingsResponse>;
+  appeal: ({
+    id
+  }: {
+    id: number;
+  }) => Promise<AppealResponse>;
+  appealsByPeer: ({
+    peer
+  }: {
+    peer: string;
+  }) => Promise<AppealsResponse>;
+  appeals: ({
+    limit,
+    startAfter,
+    status
+  }: {
+    limit?: number;
+    startAfter?: number;
+    status?: AppealStatus;
+  }) => Promise<AppealsResponse>;
+  pairReviewCounter: ({
+    peer,
+    reviewer
+  }: {
+    peer: string;
+    reviewer: string;
+  }) => Promise<PairReviewCounterResponse>;
+  pairReviewCounters: ({
+    limit,
+    peer,
+    startAfter
+  }: {
+    limit?: number;
+    peer: string;
+    startAfter?: string;
+  }) => Promise<PairReviewCountersResponse>;
 }
 export class TrustQueryClient implements TrustReadOnlyInterface {
   client: CosmWasmClient;
@@ -40,11 +169,34 @@ export class TrustQueryClient implements TrustReadOnlyInterface {
     this.client = client;
     this.contractAddress = contractAddress;
     this.config = this.config.bind(this);
+    this.commerceCodeIds = this.commerceCodeIds.bind(this);
+    this.commerceContracts = this.commerceContracts.bind(this);
+    this.attesters = this.attesters.bind(this);
+    this.ban = this.ban.bind(this);
+    this.bans = this.bans.bind(this);
     this.trustInfo = this.trustInfo.bind(this);
+    this.tier = this.tier.bind(this);
+    this.storeScopedTrust = this.storeScopedTrust.bind(this);
+    this.trustInfoAtHeight = this.trustInfoAtHeight.bind(this);
+    this.scoreBreakdown = this.scoreBreakdown.bind(this);
+    this.simulateScore = this.simulateScore.bind(this);
     this.stakeAmount = this.stakeAmount.bind(this);
     this.accounts = this.accounts.bind(this);
+    this.topAccounts = this.topAccounts.bind(this);
+    this.rank = this.rank.bind(this);
     this.pendingReview = this.pendingReview.bind(this);
     this.pendingReviewsByReviewer = this.pendingReviewsByReviewer.bind(this);
+    this.review = this.review.bind(this);
+    this.reviewsByPeer = this.reviewsByPeer.bind(this);
+    this.reviewsByReviewer = this.reviewsByReviewer.bind(this);
+    this.vouchesGiven = this.vouchesGiven.bind(this);
+    this.vouchesReceived = this.vouchesReceived.bind(this);
+    this.unbondings = this.unbondings.bind(this);
+    this.appeal = this.appeal.bind(this);
+    this.appealsByPeer = this.appealsByPeer.bind(this);
+    this.appeals = this.appeals.bind(this);
+    this.pairReviewCounter = this.pairReviewCounter.bind(this);
+    this.pairReviewCounters = this.pairReviewCounters.bind(this);
   }
 
   config = async (): Promise<ConfigResponse> => {
@@ -52,6 +204,46 @@ export class TrustQueryClient implements TrustReadOnlyInterface {
       config: {}
     });
   };
+  commerceCodeIds = async (): Promise<CommerceCodeIdsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      commerce_code_ids: {}
+    });
+  };
+  commerceContracts = async (): Promise<CommerceContractsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      commerce_contracts: {}
+    });
+  };
+  attesters = async (): Promise<AttestersResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      attesters: {}
+    });
+  };
+  ban = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<BanResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      ban: {
+        address
+      }
+    });
+  };
+  bans = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }): Promise<BansResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      bans: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
   trustInfo = async ({
     address
   }: {
@@ -63,6 +255,67 @@ export class TrustQueryClient implements TrustReadOnlyInterface {
       }
     });
   };
+  tier = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<TierResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      tier: {
+        address
+      }
+    });
+  };
+  storeScopedTrust = async ({
+    address,
+    commerceContract
+  }: {
+    address: string;
+    commerceContract: string;
+  }): Promise<StoreScopedTrustResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      store_scoped_trust: {
+        address,
+        commerce_contract: commerceContract
+      }
+    });
+  };
+  trustInfoAtHeight = async ({
+    address,
+    height
+  }: {
+    address: string;
+    height: number;
+  }): Promise<TrustInfoAtHeightResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      trust_info_at_height: {
+        address,
+        height
+      }
+    });
+  };
+  scoreBreakdown = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<ScoreBreakdownResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      score_breakdown: {
+        address
+      }
+    });
+  };
+  simulateScore = async ({
+    data
+  }: {
+    data: TrustData;
+  }): Promise<SimulateScoreResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      simulate_score: {
+        data
+      }
+    });
+  };
   stakeAmount = async ({
     address
   }: {
@@ -79,6 +332,31 @@ export class TrustQueryClient implements TrustReadOnlyInterface {
       accounts: {}
     });
   };
+  topAccounts = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }): Promise<TopAccountsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      top_accounts: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  rank = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<RankResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      rank: {
+        address
+      }
+    });
+  };
   pendingReview = async ({
     peer
   }: {
@@ -101,51 +379,376 @@ export class TrustQueryClient implements TrustReadOnlyInterface {
       }
     });
   };
+  review = async ({
+    id
+  }: {
+    id: number;
+  }): Promise<ReviewResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      review: {
+        id
+      }
+    });
+  };
+  reviewsByPeer = async ({
+    limit,
+    peer,
+    startAfter
+  }: {
+    limit?: number;
+    peer: string;
+    startAfter?: number;
+  }): Promise<ReviewsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      reviews_by_peer: {
+        limit,
+        peer,
+        start_after: startAfter
+      }
+    });
+  };
+  reviewsByReviewer = async ({
+    limit,
+    reviewer,
+    startAfter
+  }: {
+    limit?: number;
+    reviewer: string;
+    startAfter?: number;
+  }): Promise<ReviewsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      reviews_by_reviewer: {
+        limit,
+        reviewer,
+        start_after: startAfter
+      }
+    });
+  };
+  vouchesGiven = async ({
+    voucher
+  }: {
+    voucher: string;
+  }): Promise<VouchesResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      vouches_given: {
+        voucher
+      }
+    });
+  };
+  vouchesReceived = async ({
+    vouchee
+  }: {
+    vouchee: string;
+  }): Promise<VouchesResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      vouches_received: {
+        vouchee
+      }
+    });
+  };
+  unbondings = async ({
+    voucher
+  }: {
+    voucher: string;
+  }): Promise<UnbondingsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      unbondings: {
+        voucher
+      }
+    });
+  };
+  appeal = async ({
+    id
+  }: {
+    id: number;
+  }): Promise<AppealResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      appeal: {
+        id
+      }
+    });
+  };
+  appealsByPeer = async ({
+    peer
+  }: {
+    peer: string;
+  }): Promise<AppealsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      appeals_by_peer: {
+        peer
+      }
+    });
+  };
+  appeals = async ({
+    limit,
+    startAfter,
+    status
+  }: {
+    limit?: number;
+    startAfter?: number;
+    status?: AppealStatus;
+  }): Promise<AppealsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      appeals: {
+        limit,
+        start_after: startAfter,
+        status
+      }
+    });
+  };
+  pairReviewCounter = async ({
+    peer,
+    reviewer
+  }: {
+    peer: string;
+    reviewer: string;
+  }): Promise<PairReviewCounterResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      pair_review_counter: {
+        peer,
+        reviewer
+      }
+    });
+  };
+  pairReviewCounters = async ({
+    limit,
+    peer,
+    startAfter
+  }: {
+    limit?: number;
+    peer: string;
+    startAfter?: string;
+  }): Promise<PairReviewCountersResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      pair_review_counters: {
+        limit,
+        peer,
+        start_after: startAfter
+      }
+    });
+  };
 }
 export interface TrustInterface extends TrustReadOnlyInterface {
   contractAddress: string;
   sender: string;
   updateConfig: ({
     admin,
-    commerceCodeId,
     maintainer,
     maxRating,
     maxStakedDays,
-    maxStakedTokens,
+    minSyncInterval,
+    ratingHalfLife,
     reviewInterval,
-    stakingContract,
-    trustScoreParams
+    scoreModel,
+    stakingSources
   }: {
     admin: string;
-    commerceCodeId: number;
-    maintainer: string;
+    maintainer?: string;
     maxRating: number;
     maxStakedDays: number;
-    maxStakedTokens: Uint128;
+    minSyncInterval: number;
+    ratingHalfLife: number;
     reviewInterval: number;
-    stakingContract: string;
-    trustScoreParams: TrustScoreParams;
+    scoreModel: ScoreModel;
+    stakingSources: StakingSourceMsg[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  updateReviewParams: ({
+    appealWindow,
+    pairReviewLimit,
+    reviewWeight,
+    reviewerEligibility
+  }: {
+    appealWindow: number;
+    pairReviewLimit: PairReviewLimit;
+    reviewWeight: ReviewWeightParams;
+    reviewerEligibility: ReviewerEligibility;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  updateVouchingParams: ({
+    vouching
+  }: {
+    vouching: VouchingParamsMsg;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  updateTiers: ({
+    tiers
+  }: {
+    tiers: Tier[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  addCommerceCodeId: ({
+    codeId
+  }: {
+    codeId: number;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  removeCommerceCodeId: ({
+    codeId
+  }: {
+    codeId: number;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  addCommerceContract: ({
+    address
+  }: {
+    address: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  removeCommerceContract: ({
+    address
+  }: {
+    address: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  addAttester: ({
+    address,
+    name,
+    scoreBonus
+  }: {
+    address: string;
+    name: string;
+    scoreBonus: number;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  removeAttester: ({
+    address
+  }: {
+    address: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  decideAppeal: ({
+    decision,
+    id
+  }: {
+    decision: AppealDecision;
+    id: number;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  ban: ({
+    address,
+    expiresAt,
+    reason
+  }: {
+    address: string;
+    expiresAt?: Timestamp;
+    reason: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  unban: ({
+    address
+  }: {
+    address: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  slash: ({
+    address,
+    limit,
+    startAfter
+  }: {
+    address: string;
+    limit?: number;
+    startAfter?: string;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
   updateStakingInfo: ({
     address
   }: {
     address: string;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  updateStakingInfoBatch: ({
+    addresses
+  }: {
+    addresses: string[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  updateStakingInfoNext: ({
+    limit
+  }: {
+    limit: number;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  register: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  unvouch: ({
+    address,
+    amount
+  }: {
+    address: string;
+    amount?: Uint128;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  claimUnvouched: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  receive: ({
+    amount,
+    msg,
+    sender
+  }: {
+    amount: Uint128;
+    msg: Binary;
+    sender: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  attest: ({
+    address,
+    expiresAt,
+    kind,
+    uri
+  }: {
+    address: string;
+    expiresAt?: Timestamp;
+    kind: string;
+    uri?: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  revokeAttestation: ({
+    address,
+    attester,
+    kind
+  }: {
+    address: string;
+    attester?: string;
+    kind: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  stakeChangeHook: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
   registerPendingReview: ({
     orderId,
+    orderValue,
     peer,
+    peerRole,
     reviewer
   }: {
     orderId: number;
+    orderValue?: Uint128;
     peer: string;
+    peerRole?: TradeRole;
     reviewer: string;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
   review: ({
     address,
+    comment,
     review
   }: {
     address: string;
+    comment?: ReviewComment;
+    review: ReviewResult;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  commitReview: ({
+    address,
+    commitment
+  }: {
+    address: string;
+    commitment: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  revealReview: ({
+    address,
+    comment,
+    review,
+    salt
+  }: {
+    address: string;
+    comment?: ReviewComment;
     review: ReviewResult;
+    salt: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  appealReview: ({
+    id,
+    reason
+  }: {
+    id: number;
+    reason?: ReviewComment;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  replyToReview: ({
+    id,
+    reply
+  }: {
+    id: number;
+    reply: ReviewComment;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  settleReview: ({
+    address
+  }: {
+    address: string;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
 }
 export class TrustClient extends TrustQueryClient implements TrustInterface {
@@ -159,43 +762,243 @@ export class TrustClient extends TrustQueryClient implements TrustInterface {
     this.sender = sender;
     this.contractAddress = contractAddress;
     this.updateConfig = this.updateConfig.bind(this);
+    this.updateReviewParams = this.updateReviewParams.bind(this);
+    this.updateVouchingParams = this.updateVouchingParams.bind(this);
+    this.updateTiers = this.updateTiers.bind(this);
+    this.addCommerceCodeId = this.addCommerceCodeId.bind(this);
+    this.removeCommerceCodeId = this.removeCommerceCodeId.bind(this);
+    this.addCommerceContract = this.addCommerceContract.bind(this);
+    this.removeCommerceContract = this.removeCommerceContract.bind(this);
+    this.addAttester = this.addAttester.bind(this);
+    this.removeAttester = this.removeAttester.bind(this);
+    this.decideAppeal = this.decideAppeal.bind(this);
+    this.ban = this.ban.bind(this);
+    this.unban = this.unban.bind(this);
+    this.slash = this.slash.bind(this);
     this.updateStakingInfo = this.updateStakingInfo.bind(this);
+    this.updateStakingInfoBatch = this.updateStakingInfoBatch.bind(this);
+    this.updateStakingInfoNext = this.updateStakingInfoNext.bind(this);
+    this.register = this.register.bind(this);
+    this.unvouch = this.unvouch.bind(this);
+    this.claimUnvouched = this.claimUnvouched.bind(this);
+    this.receive = this.receive.bind(this);
+    this.attest = this.attest.bind(this);
+    this.revokeAttestation = this.revokeAttestation.bind(this);
+    this.stakeChangeHook = this.stakeChangeHook.bind(this);
     this.registerPendingReview = this.registerPendingReview.bind(this);
     this.review = this.review.bind(this);
+    this.commitReview = this.commitReview.bind(this);
+    this.revealReview = this.revealReview.bind(this);
+    this.appealReview = this.appealReview.bind(this);
+    this.replyToReview = this.replyToReview.bind(this);
+    this.settleReview = this.settleReview.bind(this);
   }
 
   updateConfig = async ({
     admin,
-    commerceCodeId,
     maintainer,
     maxRating,
     maxStakedDays,
-    maxStakedTokens,
+    minSyncInterval,
+    ratingHalfLife,
     reviewInterval,
-    stakingContract,
-    trustScoreParams
+    scoreModel,
+    stakingSources
   }: {
     admin: string;
-    commerceCodeId: number;
-    maintainer: string;
+    maintainer?: string;
     maxRating: number;
     maxStakedDays: number;
-    maxStakedTokens: Uint128;
+    minSyncInterval: number;
+    ratingHalfLife: number;
     reviewInterval: number;
-    stakingContract: string;
-    trustScoreParams: TrustScoreParams;
+    scoreModel: ScoreModel;
+    stakingSources: StakingSourceMsg[];
   }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       update_config: {
         admin,
-        commerce_code_id: commerceCodeId,
         maintainer,
         max_rating: maxRating,
         max_staked_days: maxStakedDays,
-        max_staked_tokens: maxStakedTokens,
+        min_sync_interval: minSyncInterval,
+        rating_half_life: ratingHalfLife,
         review_interval: reviewInterval,
-        staking_contract: stakingContract,
-        trust_score_params: trustScoreParams
+        score_model: scoreModel,
+        staking_sources: stakingSources
+      }
+    }, fee, memo, funds);
+  };
+  updateReviewParams = async ({
+    appealWindow,
+    pairReviewLimit,
+    reviewWeight,
+    reviewerEligibility
+  }: {
+    appealWindow: number;
+    pairReviewLimit: PairReviewLimit;
+    reviewWeight: ReviewWeightParams;
+    reviewerEligibility: ReviewerEligibility;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_review_params: {
+        appeal_window: appealWindow,
+        pair_review_limit: pairReviewLimit,
+        review_weight: reviewWeight,
+        reviewer_eligibility: reviewerEligibility
+      }
+    }, fee, memo, funds);
+  };
+  updateVouchingParams = async ({
+    vouching
+  }: {
+    vouching: VouchingParamsMsg;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_vouching_params: {
+        vouching
+      }
+    }, fee, memo, funds);
+  };
+  updateTiers = async ({
+    tiers
+  }: {
+    tiers: Tier[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_tiers: {
+        tiers
+      }
+    }, fee, memo, funds);
+  };
+  addCommerceCodeId = async ({
+    codeId
+  }: {
+    codeId: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      add_commerce_code_id: {
+        code_id: codeId
+      }
+    }, fee, memo, funds);
+  };
+  removeCommerceCodeId = async ({
+    codeId
+  }: {
+    codeId: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      remove_commerce_code_id: {
+        code_id: codeId
+      }
+    }, fee, memo, funds);
+  };
+  addCommerceContract = async ({
+    address
+  }: {
+    address: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      add_commerce_contract: {
+        address
+      }
+    }, fee, memo, funds);
+  };
+  removeCommerceContract = async ({
+    address
+  }: {
+    address: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      remove_commerce_contract: {
+        address
+      }
+    }, fee, memo, funds);
+  };
+  addAttester = async ({
+    address,
+    name,
+    scoreBonus
+  }: {
+    address: string;
+    name: string;
+    scoreBonus: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      add_attester: {
+        address,
+        name,
+        score_bonus: scoreBonus
+      }
+    }, fee, memo, funds);
+  };
+  removeAttester = async ({
+    address
+  }: {
+    address: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      remove_attester: {
+        address
+      }
+    }, fee, memo, funds);
+  };
+  decideAppeal = async ({
+    decision,
+    id
+  }: {
+    decision: AppealDecision;
+    id: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      decide_appeal: {
+        decision,
+        id
+      }
+    }, fee, memo, funds);
+  };
+  ban = async ({
+    address,
+    expiresAt,
+    reason
+  }: {
+    address: string;
+    expiresAt?: Timestamp;
+    reason: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      ban: {
+        address,
+        expires_at: expiresAt,
+        reason
+      }
+    }, fee, memo, funds);
+  };
+  unban = async ({
+    address
+  }: {
+    address: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      unban: {
+        address
+      }
+    }, fee, memo, funds);
+  };
+  slash = async ({
+    address,
+    limit,
+    startAfter
+  }: {
+    address: string;
+    limit?: number;
+    startAfter?: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      slash: {
+        address,
+        limit,
+        start_after: startAfter
       }
     }, fee, memo, funds);
   };
@@ -210,35 +1013,222 @@ export class TrustClient extends TrustQueryClient implements TrustInterface {
       }
     }, fee, memo, funds);
   };
+  updateStakingInfoBatch = async ({
+    addresses
+  }: {
+    addresses: string[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_staking_info_batch: {
+        addresses
+      }
+    }, fee, memo, funds);
+  };
+  updateStakingInfoNext = async ({
+    limit
+  }: {
+    limit: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_staking_info_next: {
+        limit
+      }
+    }, fee, memo, funds);
+  };
+  register = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      register: {}
+    }, fee, memo, funds);
+  };
+  unvouch = async ({
+    address,
+    amount
+  }: {
+    address: string;
+    amount?: Uint128;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      unvouch: {
+        address,
+        amount
+      }
+    }, fee, memo, funds);
+  };
+  claimUnvouched = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      claim_unvouched: {}
+    }, fee, memo, funds);
+  };
+  receive = async ({
+    amount,
+    msg,
+    sender
+  }: {
+    amount: Uint128;
+    msg: Binary;
+    sender: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      receive: {
+        amount,
+        msg,
+        sender
+      }
+    }, fee, memo, funds);
+  };
+  attest = async ({
+    address,
+    expiresAt,
+    kind,
+    uri
+  }: {
+    address: string;
+    expiresAt?: Timestamp;
+    kind: string;
+    uri?: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      attest: {
+        address,
+        expires_at: expiresAt,
+        kind,
+        uri
+      }
+    }, fee, memo, funds);
+  };
+  revokeAttestation = async ({
+    address,
+    attester,
+    kind
+  }: {
+    address: string;
+    attester?: string;
+    kind: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      revoke_attestation: {
+        address,
+        attester,
+        kind
+      }
+    }, fee, memo, funds);
+  };
+  stakeChangeHook = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      stake_change_hook: {}
+    }, fee, memo, funds);
+  };
   registerPendingReview = async ({
     orderId,
+    orderValue,
     peer,
+    peerRole,
     reviewer
   }: {
     orderId: number;
+    orderValue?: Uint128;
     peer: string;
+    peerRole?: TradeRole;
     reviewer: string;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       register_pending_review: {
         order_id: orderId,
+        order_value: orderValue,
         peer,
+        peer_role: peerRole,
         reviewer
       }
     }, fee, memo, funds);
   };
   review = async ({
     address,
+    comment,
     review
   }: {
     address: string;
+    comment?: ReviewComment;
     review: ReviewResult;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       review: {
         address,
+        comment,
         review
       }
     }, fee, memo, funds);
   };
+  commitReview = async ({
+    address,
+    commitment
+  }: {
+    address: string;
+    commitment: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      commit_review: {
+        address,
+        commitment
+      }
+    }, fee, memo, funds);
+  };
+  revealReview = async ({
+    address,
+    comment,
+    review,
+    salt
+  }: {
+    address: string;
+    comment?: ReviewComment;
+    review: ReviewResult;
+    salt: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      reveal_review: {
+        address,
+        comment,
+        review,
+        salt
+      }
+    }, fee, memo, funds);
+  };
+  appealReview = async ({
+    id,
+    reason
+  }: {
+    id: number;
+    reason?: ReviewComment;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      appeal_review: {
+        id,
+        reason
+      }
+    }, fee, memo, funds);
+  };
+  replyToReview = async ({
+    id,
+    reply
+  }: {
+    id: number;
+    reply: ReviewComment;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      reply_to_review: {
+        id,
+        reply
+      }
+    }, fee, memo, funds);
+  };
+  settleReview = async ({
+    address
+  }: {
+    address: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      settle_review: {
+        address
+      }
+    }, fee, memo, funds);
+  };
 }
\ No newline at end of file
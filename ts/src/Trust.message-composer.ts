@@ -8,52 +8,229 @@ import { Coin } from "@cosmjs/amino";
 import { MsgExecuteContractEncodeObject } from "cosmwasm";
 import { MsgExecuteContract } from "cosmjs-types/cosmwasm/wasm/v1/tx";
 import { toUtf8 } from "@cosmjs/encoding";
-import { Uint128, InstantiateMsg, TrustScoreParams, ExecuteMsg, ReviewResult, QueryMsg, Addr, AccountsResponse, ConfigResponse, Config, Timestamp, Uint64, PendingReviewResponse, PendingReview, PendingReviewsResponse, StakeAmountResponse, Decimal, TrustInfoResponse, TrustInfo, TrustData } from "./Trust.types";
+import { Decimal, Uint128, IneligibleReviewPolicy, ScoreModel, StakeSourceMsg, InstantiateMsg, PairReviewLimit, ReviewWeightParams, ReviewerEligibility, LinearModelParams, LogStakeModelParams, BayesianRatingModelParams, StakingSourceMsg, Tier, VouchingParamsMsg, VoucherEligibility, ExecuteMsg, AppealDecision, Timestamp, Uint64, Binary, StakeChangedHookMsg, Addr, TradeRole, ReviewComment, ReviewResult, Cw20ReceiveMsg, QueryMsg, StakeSource, AppealStatus, TrustData, Attestation, Ban, RoleRating, SourceStake, MigrateMsg, AccountsResponse, AppealResponse, Appeal, AppealsResponse, AttestersResponse, AttesterInfo, Attester, BanResponse, BansResponse, BanInfo, CommerceCodeIdsResponse, CommerceContractsResponse, ConfigResponse, Config, StakingSource, VouchingParams, PairReviewCounterResponse, PairReviewCounter, PairReviewCountersResponse, ReviewerCounter, PendingReviewResponse, PendingReview, PendingReviewsResponse, RankResponse, ReviewResponse, Review, ReviewsResponse, ScoreBreakdownResponse, ScoreBreakdown, ScoreComponent, SimulateScoreResponse, StakeAmountResponse, SourceStakeAmount, StoreScopedTrustResponse, StoreRating, TierResponse, TopAccountsResponse, AccountScore, TrustInfoResponse, RoleScores, TrustInfo, TrustInfoAtHeightResponse, UnbondingsResponse, Unbonding, VouchesResponse, Vouch } from "./Trust.types";
 export interface TrustMessage {
   contractAddress: string;
   sender: string;
   updateConfig: ({
     admin,
-    commerceCodeId,
     maintainer,
     maxRating,
     maxStakedDays,
-    maxStakedTokens,
+    minSyncInterval,
+    ratingHalfLife,
     reviewInterval,
-    stakingContract,
-    trustScoreParams
+    scoreModel,
+    stakingSources
   }: {
     admin: string;
-    commerceCodeId: number;
-    maintainer: string;
+    maintainer?: string;
     maxRating: number;
     maxStakedDays: number;
-    maxStakedTokens: Uint128;
+    minSyncInterval: number;
+    ratingHalfLife: number;
     reviewInterval: number;
-    stakingContract: string;
-    trustScoreParams: TrustScoreParams;
+    scoreModel: ScoreModel;
+    stakingSources: StakingSourceMsg[];
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateReviewParams: ({
+    appealWindow,
+    pairReviewLimit,
+    reviewWeight,
+    reviewerEligibility
+  }: {
+    appealWindow: number;
+    pairReviewLimit: PairReviewLimit;
+    reviewWeight: ReviewWeightParams;
+    reviewerEligibility: ReviewerEligibility;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateVouchingParams: ({
+    vouching
+  }: {
+    vouching: VouchingParamsMsg;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateTiers: ({
+    tiers
+  }: {
+    tiers: Tier[];
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  addCommerceCodeId: ({
+    codeId
+  }: {
+    codeId: number;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  removeCommerceCodeId: ({
+    codeId
+  }: {
+    codeId: number;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  addCommerceContract: ({
+    address
+  }: {
+    address: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  removeCommerceContract: ({
+    address
+  }: {
+    address: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  addAttester: ({
+    address,
+    name,
+    scoreBonus
+  }: {
+    address: string;
+    name: string;
+    scoreBonus: number;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  removeAttester: ({
+    address
+  }: {
+    address: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  decideAppeal: ({
+    decision,
+    id
+  }: {
+    decision: AppealDecision;
+    id: number;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  ban: ({
+    address,
+    expiresAt,
+    reason
+  }: {
+    address: string;
+    expiresAt?: Timestamp;
+    reason: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  unban: ({
+    address
+  }: {
+    address: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  slash: ({
+    address,
+    limit,
+    startAfter
+  }: {
+    address: string;
+    limit?: number;
+    startAfter?: string;
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
   updateStakingInfo: ({
     address
   }: {
     address: string;
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateStakingInfoBatch: ({
+    addresses
+  }: {
+    addresses: string[];
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateStakingInfoNext: ({
+    limit
+  }: {
+    limit: number;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  register: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  unvouch: ({
+    address,
+    amount
+  }: {
+    address: string;
+    amount?: Uint128;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  claimUnvouched: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  receive: ({
+    amount,
+    msg,
+    sender
+  }: {
+    amount: Uint128;
+    msg: Binary;
+    sender: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  attest: ({
+    address,
+    expiresAt,
+    kind,
+    uri
+  }: {
+    address: string;
+    expiresAt?: Timestamp;
+    kind: string;
+    uri?: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  revokeAttestation: ({
+    address,
+    attester,
+    kind
+  }: {
+    address: string;
+    attester?: string;
+    kind: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  stakeChangeHook: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
   registerPendingReview: ({
     orderId,
+    orderValue,
     peer,
+    peerRole,
     reviewer
   }: {
     orderId: number;
+    orderValue?: Uint128;
     peer: string;
+    peerRole?: TradeRole;
     reviewer: string;
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
   review: ({
     address,
+    comment,
     review
   }: {
     address: string;
+    comment?: ReviewComment;
     review: ReviewResult;
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  commitReview: ({
+    address,
+    commitment
+  }: {
+    address: string;
+    commitment: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  revealReview: ({
+    address,
+    comment,
+    review,
+    salt
+  }: {
+    address: string;
+    comment?: ReviewComment;
+    review: ReviewResult;
+    salt: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  appealReview: ({
+    id,
+    reason
+  }: {
+    id: number;
+    reason?: ReviewComment;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  replyToReview: ({
+    id,
+    reply
+  }: {
+    id: number;
+    reply: ReviewComment;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  settleReview: ({
+    address
+  }: {
+    address: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
 }
 export class TrustMessageComposer implements TrustMessage {
   sender: string;
@@ -63,31 +240,58 @@ export class TrustMessageComposer implements TrustMessage {
     this.sender = sender;
     this.contractAddress = contractAddress;
     this.updateConfig = this.updateConfig.bind(this);
+    this.updateReviewParams = this.updateReviewParams.bind(this);
+    this.updateVouchingParams = this.updateVouchingParams.bind(this);
+    this.updateTiers = this.updateTiers.bind(this);
+    this.addCommerceCodeId = this.addCommerceCodeId.bind(this);
+    this.removeCommerceCodeId = this.removeCommerceCodeId.bind(this);
+    this.addCommerceContract = this.addCommerceContract.bind(this);
+    this.removeCommerceContract = this.removeCommerceContract.bind(this);
+    this.addAttester = this.addAttester.bind(this);
+    this.removeAttester = this.removeAttester.bind(this);
+    this.decideAppeal = this.decideAppeal.bind(this);
+    this.ban = this.ban.bind(this);
+    this.unban = this.unban.bind(this);
+    this.slash = this.slash.bind(this);
     this.updateStakingInfo = this.updateStakingInfo.bind(this);
+    this.updateStakingInfoBatch = this.updateStakingInfoBatch.bind(this);
+    this.updateStakingInfoNext = this.updateStakingInfoNext.bind(this);
+    this.register = this.register.bind(this);
+    this.unvouch = this.unvouch.bind(this);
+    this.claimUnvouched = this.claimUnvouched.bind(this);
+    this.receive = this.receive.bind(this);
+    this.attest = this.attest.bind(this);
+    this.revokeAttestation = this.revokeAttestation.bind(this);
+    this.stakeChangeHook = this.stakeChangeHook.bind(this);
     this.registerPendingReview = this.registerPendingReview.bind(this);
     this.review = this.review.bind(this);
+    this.commitReview = this.commitReview.bind(this);
+    this.revealReview = this.revealReview.bind(this);
+    this.appealReview = this.appealReview.bind(this);
+    this.replyToReview = this.replyToReview.bind(this);
+    this.settleReview = this.settleReview.bind(this);
   }
 
   updateConfig = ({
     admin,
-    commerceCodeId,
     maintainer,
     maxRating,
     maxStakedDays,
-    maxStakedTokens,
+    minSyncInterval,
+    ratingHalfLife,
     reviewInterval,
-    stakingContract,
-    trustScoreParams
+    scoreModel,
+    stakingSources
   }: {
     admin: string;
-    commerceCodeId: number;
-    maintainer: string;
+    maintainer?: string;
     maxRating: number;
     maxStakedDays: number;
-    maxStakedTokens: Uint128;
+    minSyncInterval: number;
+    ratingHalfLife: number;
     reviewInterval: number;
-    stakingContract: string;
-    trustScoreParams: TrustScoreParams;
+    scoreModel: ScoreModel;
+    stakingSources: StakingSourceMsg[];
   }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -97,14 +301,291 @@ export class TrustMessageComposer implements TrustMessage {
         msg: toUtf8(JSON.stringify({
           update_config: {
             admin,
-            commerce_code_id: commerceCodeId,
             maintainer,
             max_rating: maxRating,
             max_staked_days: maxStakedDays,
-            max_staked_tokens: maxStakedTokens,
+            min_sync_interval: minSyncInterval,
+            rating_half_life: ratingHalfLife,
             review_interval: reviewInterval,
-            staking_contract: stakingContract,
-            trust_score_params: trustScoreParams
+            score_model: scoreModel,
+            staking_sources: stakingSources
+          }
+        })),
+        funds
+      })
+    };
+  };
+  updateReviewParams = ({
+    appealWindow,
+    pairReviewLimit,
+    reviewWeight,
+    reviewerEligibility
+  }: {
+    appealWindow: number;
+    pairReviewLimit: PairReviewLimit;
+    reviewWeight: ReviewWeightParams;
+    reviewerEligibility: ReviewerEligibility;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_review_params: {
+            appeal_window: appealWindow,
+            pair_review_limit: pairReviewLimit,
+            review_weight: reviewWeight,
+            reviewer_eligibility: reviewerEligibility
+          }
+        })),
+        funds
+      })
+    };
+  };
+  updateVouchingParams = ({
+    vouching
+  }: {
+    vouching: VouchingParamsMsg;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_vouching_params: {
+            vouching
+          }
+        })),
+        funds
+      })
+    };
+  };
+  updateTiers = ({
+    tiers
+  }: {
+    tiers: Tier[];
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_tiers: {
+            tiers
+          }
+        })),
+        funds
+      })
+    };
+  };
+  addCommerceCodeId = ({
+    codeId
+  }: {
+    codeId: number;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          add_commerce_code_id: {
+            code_id: codeId
+          }
+        })),
+        funds
+      })
+    };
+  };
+  removeCommerceCodeId = ({
+    codeId
+  }: {
+    codeId: number;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          remove_commerce_code_id: {
+            code_id: codeId
+          }
+        })),
+        funds
+      })
+    };
+  };
+  addCommerceContract = ({
+    address
+  }: {
+    address: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          add_commerce_contract: {
+            address
+          }
+        })),
+        funds
+      })
+    };
+  };
+  removeCommerceContract = ({
+    address
+  }: {
+    address: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          remove_commerce_contract: {
+            address
+          }
+        })),
+        funds
+      })
+    };
+  };
+  addAttester = ({
+    address,
+    name,
+    scoreBonus
+  }: {
+    address: string;
+    name: string;
+    scoreBonus: number;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          add_attester: {
+            address,
+            name,
+            score_bonus: scoreBonus
+          }
+        })),
+        funds
+      })
+    };
+  };
+  removeAttester = ({
+    address
+  }: {
+    address: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          remove_attester: {
+            address
+          }
+        })),
+        funds
+      })
+    };
+  };
+  decideAppeal = ({
+    decision,
+    id
+  }: {
+    decision: AppealDecision;
+    id: number;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          decide_appeal: {
+            decision,
+            id
+          }
+        })),
+        funds
+      })
+    };
+  };
+  ban = ({
+    address,
+    expiresAt,
+    reason
+  }: {
+    address: string;
+    expiresAt?: Timestamp;
+    reason: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          ban: {
+            address,
+            expires_at: expiresAt,
+            reason
+          }
+        })),
+        funds
+      })
+    };
+  };
+  unban = ({
+    address
+  }: {
+    address: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          unban: {
+            address
+          }
+        })),
+        funds
+      })
+    };
+  };
+  slash = ({
+    address,
+    limit,
+    startAfter
+  }: {
+    address: string;
+    limit?: number;
+    startAfter?: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          slash: {
+            address,
+            limit,
+            start_after: startAfter
           }
         })),
         funds
@@ -130,13 +611,194 @@ export class TrustMessageComposer implements TrustMessage {
       })
     };
   };
+  updateStakingInfoBatch = ({
+    addresses
+  }: {
+    addresses: string[];
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_staking_info_batch: {
+            addresses
+          }
+        })),
+        funds
+      })
+    };
+  };
+  updateStakingInfoNext = ({
+    limit
+  }: {
+    limit: number;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_staking_info_next: {
+            limit
+          }
+        })),
+        funds
+      })
+    };
+  };
+  register = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          register: {}
+        })),
+        funds
+      })
+    };
+  };
+  unvouch = ({
+    address,
+    amount
+  }: {
+    address: string;
+    amount?: Uint128;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          unvouch: {
+            address,
+            amount
+          }
+        })),
+        funds
+      })
+    };
+  };
+  claimUnvouched = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          claim_unvouched: {}
+        })),
+        funds
+      })
+    };
+  };
+  receive = ({
+    amount,
+    msg,
+    sender
+  }: {
+    amount: Uint128;
+    msg: Binary;
+    sender: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          receive: {
+            amount,
+            msg,
+            sender
+          }
+        })),
+        funds
+      })
+    };
+  };
+  attest = ({
+    address,
+    expiresAt,
+    kind,
+    uri
+  }: {
+    address: string;
+    expiresAt?: Timestamp;
+    kind: string;
+    uri?: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          attest: {
+            address,
+            expires_at: expiresAt,
+            kind,
+            uri
+          }
+        })),
+        funds
+      })
+    };
+  };
+  revokeAttestation = ({
+    address,
+    attester,
+    kind
+  }: {
+    address: string;
+    attester?: string;
+    kind: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          revoke_attestation: {
+            address,
+            attester,
+            kind
+          }
+        })),
+        funds
+      })
+    };
+  };
+  stakeChangeHook = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          stake_change_hook: {}
+        })),
+        funds
+      })
+    };
+  };
   registerPendingReview = ({
     orderId,
+    orderValue,
     peer,
+    peerRole,
     reviewer
   }: {
     orderId: number;
+    orderValue?: Uint128;
     peer: string;
+    peerRole?: TradeRole;
     reviewer: string;
   }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
@@ -147,7 +809,9 @@ export class TrustMessageComposer implements TrustMessage {
         msg: toUtf8(JSON.stringify({
           register_pending_review: {
             order_id: orderId,
+            order_value: orderValue,
             peer,
+            peer_role: peerRole,
             reviewer
           }
         })),
@@ -157,9 +821,11 @@ export class TrustMessageComposer implements TrustMessage {
   };
   review = ({
     address,
+    comment,
     review
   }: {
     address: string;
+    comment?: ReviewComment;
     review: ReviewResult;
   }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
@@ -170,6 +836,7 @@ export class TrustMessageComposer implements TrustMessage {
         msg: toUtf8(JSON.stringify({
           review: {
             address,
+            comment,
             review
           }
         })),
@@ -177,4 +844,117 @@ export class TrustMessageComposer implements TrustMessage {
       })
     };
   };
+  commitReview = ({
+    address,
+    commitment
+  }: {
+    address: string;
+    commitment: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          commit_review: {
+            address,
+            commitment
+          }
+        })),
+        funds
+      })
+    };
+  };
+  revealReview = ({
+    address,
+    comment,
+    review,
+    salt
+  }: {
+    address: string;
+    comment?: ReviewComment;
+    review: ReviewResult;
+    salt: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          reveal_review: {
+            address,
+            comment,
+            review,
+            salt
+          }
+        })),
+        funds
+      })
+    };
+  };
+  appealReview = ({
+    id,
+    reason
+  }: {
+    id: number;
+    reason?: ReviewComment;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          appeal_review: {
+            id,
+            reason
+          }
+        })),
+        funds
+      })
+    };
+  };
+  replyToReview = ({
+    id,
+    reply
+  }: {
+    id: number;
+    reply: ReviewComment;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          reply_to_review: {
+            id,
+            reply
+          }
+        })),
+        funds
+      })
+    };
+  };
+  settleReview = ({
+    address
+  }: {
+    address: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          settle_review: {
+            address
+          }
+        })),
+        funds
+      })
+    };
+  };
 }
\ No newline at end of file
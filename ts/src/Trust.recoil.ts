@@ -6,7 +6,7 @@
 
 import { selectorFamily } from "recoil";
 import { cosmWasmClient } from "./chain";
-import { Uint128, InstantiateMsg, TrustScoreParams, ExecuteMsg, ReviewResult, QueryMsg, Addr, AccountsResponse, ConfigResponse, Config, Timestamp, Uint64, PendingReviewResponse, PendingReview, PendingReviewsResponse, StakeAmountResponse, Decimal, TrustInfoResponse, TrustInfo, TrustData } from "./Trust.types";
+import { Decimal, Uint128, IneligibleReviewPolicy, ScoreModel, StakeSourceMsg, InstantiateMsg, PairReviewLimit, ReviewWeightParams, ReviewerEligibility, LinearModelParams, LogStakeModelParams, BayesianRatingModelParams, StakingSourceMsg, Tier, VouchingParamsMsg, VoucherEligibility, ExecuteMsg, AppealDecision, Timestamp, Uint64, Binary, StakeChangedHookMsg, Addr, TradeRole, ReviewComment, ReviewResult, Cw20ReceiveMsg, QueryMsg, StakeSource, AppealStatus, TrustData, Attestation, Ban, RoleRating, SourceStake, MigrateMsg, AccountsResponse, AppealResponse, Appeal, AppealsResponse, AttestersResponse, AttesterInfo, Attester, BanResponse, BansResponse, BanInfo, CommerceCodeIdsResponse, CommerceContractsResponse, ConfigResponse, Config, StakingSource, VouchingParams, PairReviewCounterResponse, PairReviewCounter, PairReviewCountersResponse, ReviewerCounter, PendingReviewResponse, PendingReview, PendingReviewsResponse, RankResponse, ReviewResponse, Review, ReviewsResponse, ScoreBreakdownResponse, ScoreBreakdown, ScoreComponent, SimulateScoreResponse, StakeAmountResponse, SourceStakeAmount, StoreScopedTrustResponse, StoreRating, TierResponse, TopAccountsResponse, AccountScore, TrustInfoResponse, RoleScores, TrustInfo, TrustInfoAtHeightResponse, UnbondingsResponse, Unbonding, VouchesResponse, Vouch } from "./Trust.types";
 import { TrustQueryClient } from "./Trust.client";
 type QueryClientParams = {
   contractAddress: string;
@@ -36,6 +36,76 @@ export const configSelector = selectorFamily<ConfigResponse, QueryClientParams &
     return await client.config(...params);
   }
 });
+export const commerceCodeIdsSelector = selectorFamily<CommerceCodeIdsResponse, QueryClientParams & {
+  params: Parameters<TrustQueryClient["commerceCodeIds"]>;
+}>({
+  key: "trustCommerceCodeIds",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.commerceCodeIds(...params);
+  }
+});
+export const commerceContractsSelector = selectorFamily<CommerceContractsResponse, QueryClientParams & {
+  params: Parameters<TrustQueryClient["commerceContracts"]>;
+}>({
+  key: "trustCommerceContracts",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.commerceContracts(...params);
+  }
+});
+export const attestersSelector = selectorFamily<AttestersResponse, QueryClientParams & {
+  params: Parameters<TrustQueryClient["attesters"]>;
+}>({
+  key: "trustAttesters",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.attesters(...params);
+  }
+});
+export const banSelector = selectorFamily<BanResponse, QueryClientParams & {
+  params: Parameters<TrustQueryClient["ban"]>;
+}>({
+  key: "trustBan",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.ban(...params);
+  }
+});
+export const bansSelector = selectorFamily<BansResponse, QueryClientParams & {
+  params: Parameters<TrustQueryClient["bans"]>;
+}>({
+  key: "trustBans",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.bans(...params);
+  }
+});
 export const trustInfoSelector = selectorFamily<TrustInfoResponse, QueryClientParams & {
   params: Parameters<TrustQueryClient["trustInfo"]>;
 }>({
@@ -50,6 +120,76 @@ export const trustInfoSelector = selectorFamily<TrustInfoResponse, QueryClientPa
     return await client.trustInfo(...params);
   }
 });
+export const tierSelector = selectorFamily<TierResponse, QueryClientParams & {
+  params: Parameters<TrustQueryClient["tier"]>;
+}>({
+  key: "trustTier",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.tier(...params);
+  }
+});
+export const storeScopedTrustSelector = selectorFamily<StoreScopedTrustResponse, QueryClientParams & {
+  params: Parameters<TrustQueryClient["storeScopedTrust"]>;
+}>({
+  key: "trustStoreScopedTrust",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.storeScopedTrust(...params);
+  }
+});
+export const trustInfoAtHeightSelector = selectorFamily<TrustInfoAtHeightResponse, QueryClientParams & {
+  params: Parameters<TrustQueryClient["trustInfoAtHeight"]>;
+}>({
+  key: "trustTrustInfoAtHeight",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.trustInfoAtHeight(...params);
+  }
+});
+export const scoreBreakdownSelector = selectorFamily<ScoreBreakdownResponse, QueryClientParams & {
+  params: Parameters<TrustQueryClient["scoreBreakdown"]>;
+}>({
+  key: "trustScoreBreakdown",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.scoreBreakdown(...params);
+  }
+});
+export const simulateScoreSelector = selectorFamily<SimulateScoreResponse, QueryClientParams & {
+  params: Parameters<TrustQueryClient["simulateScore"]>;
+}>({
+  key: "trustSimulateScore",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.simulateScore(...params);
+  }
+});
 export const stakeAmountSelector = selectorFamily<StakeAmountResponse, QueryClientParams & {
   params: Parameters<TrustQueryClient["stakeAmount"]>;
 }>({
@@ -78,6 +218,34 @@ export const accountsSelector = selectorFamily<AccountsResponse, QueryClientPara
     return await client.accounts(...params);
   }
 });
+export const topAccountsSelector = selectorFamily<TopAccountsResponse, QueryClientParams & {
+  params: Parameters<TrustQueryClient["topAccounts"]>;
+}>({
+  key: "trustTopAccounts",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.topAccounts(...params);
+  }
+});
+export const rankSelector = selectorFamily<RankResponse, QueryClientParams & {
+  params: Parameters<TrustQueryClient["rank"]>;
+}>({
+  key: "trustRank",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.rank(...params);
+  }
+});
 export const pendingReviewSelector = selectorFamily<PendingReviewResponse, QueryClientParams & {
   params: Parameters<TrustQueryClient["pendingReview"]>;
 }>({
@@ -105,4 +273,158 @@ export const pendingReviewsByReviewerSelector = selectorFamily<PendingReviewsRes
     const client = get(queryClient(queryClientParams));
     return await client.pendingReviewsByReviewer(...params);
   }
+});
+export const reviewSelector = selectorFamily<ReviewResponse, QueryClientParams & {
+  params: Parameters<TrustQueryClient["review"]>;
+}>({
+  key: "trustReview",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.review(...params);
+  }
+});
+export const reviewsByPeerSelector = selectorFamily<ReviewsResponse, QueryClientParams & {
+  params: Parameters<TrustQueryClient["reviewsByPeer"]>;
+}>({
+  key: "trustReviewsByPeer",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.reviewsByPeer(...params);
+  }
+});
+export const reviewsByReviewerSelector = selectorFamily<ReviewsResponse, QueryClientParams & {
+  params: Parameters<TrustQueryClient["reviewsByReviewer"]>;
+}>({
+  key: "trustReviewsByReviewer",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.reviewsByReviewer(...params);
+  }
+});
+export const vouchesGivenSelector = selectorFamily<VouchesResponse, QueryClientParams & {
+  params: Parameters<TrustQueryClient["vouchesGiven"]>;
+}>({
+  key: "trustVouchesGiven",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.vouchesGiven(...params);
+  }
+});
+export const vouchesReceivedSelector = selectorFamily<VouchesResponse, QueryClientParams & {
+  params: Parameters<TrustQueryClient["vouchesReceived"]>;
+}>({
+  key: "trustVouchesReceived",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.vouchesReceived(...params);
+  }
+});
+export const unbondingsSelector = selectorFamily<UnbondingsResponse, QueryClientParams & {
+  params: Parameters<TrustQueryClient["unbondings"]>;
+}>({
+  key: "trustUnbondings",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.unbondings(...params);
+  }
+});
+export const appealSelector = selectorFamily<AppealResponse, QueryClientParams & {
+  params: Parameters<TrustQueryClient["appeal"]>;
+}>({
+  key: "trustAppeal",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.appeal(...params);
+  }
+});
+export const appealsByPeerSelector = selectorFamily<AppealsResponse, QueryClientParams & {
+  params: Parameters<TrustQueryClient["appealsByPeer"]>;
+}>({
+  key: "trustAppealsByPeer",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.appealsByPeer(...params);
+  }
+});
+export const appealsSelector = selectorFamily<AppealsResponse, QueryClientParams & {
+  params: Parameters<TrustQueryClient["appeals"]>;
+}>({
+  key: "trustAppeals",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.appeals(...params);
+  }
+});
+export const pairReviewCounterSelector = selectorFamily<PairReviewCounterResponse, QueryClientParams & {
+  params: Parameters<TrustQueryClient["pairReviewCounter"]>;
+}>({
+  key: "trustPairReviewCounter",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.pairReviewCounter(...params);
+  }
+});
+export const pairReviewCountersSelector = selectorFamily<PairReviewCountersResponse, QueryClientParams & {
+  params: Parameters<TrustQueryClient["pairReviewCounters"]>;
+}>({
+  key: "trustPairReviewCounters",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.pairReviewCounters(...params);
+  }
 });
\ No newline at end of file
@@ -4,66 +4,349 @@
 * and run the @cosmwasm/ts-codegen generate command to regenerate this file.
 */
 
+export type Decimal = string;
 export type Uint128 = string;
+export type IneligibleReviewPolicy = "reject" | "zero_weight";
+export type ScoreModel = {
+  linear: LinearModelParams;
+} | {
+  log_stake: LogStakeModelParams;
+} | {
+  bayesian_rating: BayesianRatingModelParams;
+};
+export type StakeSourceMsg = {
+  cw20_stake: {
+    contract: string;
+  };
+} | {
+  native: {
+    denom: string;
+  };
+};
 export interface InstantiateMsg {
-  commerce_code_id: number;
-  maintainer: string;
+  appeal_window: number;
+  commerce_code_ids: number[];
+  maintainer?: string | null;
   max_rating: number;
   max_staked_days: number;
-  max_staked_tokens: Uint128;
+  min_sync_interval: number;
+  pair_review_limit: PairReviewLimit;
+  rating_half_life: number;
   review_interval: number;
-  staking_contract: string;
-  trust_score_params: TrustScoreParams;
+  review_weight: ReviewWeightParams;
+  reviewer_eligibility: ReviewerEligibility;
+  score_model: ScoreModel;
+  staking_sources: StakingSourceMsg[];
+  tiers: Tier[];
+  vouching: VouchingParamsMsg;
+}
+export interface PairReviewLimit {
+  max_weight: Decimal;
+  window: number;
+}
+export interface ReviewWeightParams {
+  max_weight: Decimal;
+  min_weight: Decimal;
+  reference_order_value: Uint128;
+  reference_score: number;
+}
+export interface ReviewerEligibility {
+  ineligible_reviews: IneligibleReviewPolicy;
+  min_account_age: number;
+  min_order_value: Uint128;
+  min_score: number;
+  min_stake: Decimal;
 }
-export interface TrustScoreParams {
+export interface LinearModelParams {
   base_score: number;
-  denom_multiplier: number;
+  max_score: number;
   min_stake_days: number;
   rating_floor_denominator: number;
   rating_multiplier: number;
   stake_amount_denominator: number;
 }
+export interface LogStakeModelParams {
+  base_score: number;
+  max_score: number;
+  rating_multiplier: number;
+  stake_amount_denominator: number;
+  stake_multiplier: number;
+}
+export interface BayesianRatingModelParams {
+  base_score: number;
+  max_score: number;
+  prior_share: Decimal;
+  prior_weight: number;
+  rating_range: number;
+  stake_amount_denominator: number;
+}
+export interface StakingSourceMsg {
+  denom_multiplier: number;
+  max_staked_tokens: Uint128;
+  source: StakeSourceMsg;
+  weight: Decimal;
+}
+export interface Tier {
+  min_score: number;
+  name: string;
+}
+export interface VouchingParamsMsg {
+  denom_multiplier: number;
+  max_bonus: number;
+  reference_score: number;
+  score_per_token: Decimal;
+  slash_share: Decimal;
+  token: string;
+  unbonding_period: number;
+  voucher_eligibility: VoucherEligibility;
+}
+export interface VoucherEligibility {
+  min_account_age: number;
+  min_score: number;
+  min_stake: Decimal;
+}
 export type ExecuteMsg = {
   update_config: {
     admin: string;
-    commerce_code_id: number;
-    maintainer: string;
+    maintainer?: string | null;
     max_rating: number;
     max_staked_days: number;
-    max_staked_tokens: Uint128;
+    min_sync_interval: number;
+    rating_half_life: number;
     review_interval: number;
-    staking_contract: string;
-    trust_score_params: TrustScoreParams;
+    score_model: ScoreModel;
+    staking_sources: StakingSourceMsg[];
+  };
+} | {
+  update_review_params: {
+    appeal_window: number;
+    pair_review_limit: PairReviewLimit;
+    review_weight: ReviewWeightParams;
+    reviewer_eligibility: ReviewerEligibility;
+  };
+} | {
+  update_vouching_params: {
+    vouching: VouchingParamsMsg;
+  };
+} | {
+  update_tiers: {
+    tiers: Tier[];
+  };
+} | {
+  add_commerce_code_id: {
+    code_id: number;
+  };
+} | {
+  remove_commerce_code_id: {
+    code_id: number;
+  };
+} | {
+  add_commerce_contract: {
+    address: string;
+  };
+} | {
+  remove_commerce_contract: {
+    address: string;
+  };
+} | {
+  add_attester: {
+    address: string;
+    name: string;
+    score_bonus: number;
+  };
+} | {
+  remove_attester: {
+    address: string;
+  };
+} | {
+  decide_appeal: {
+    decision: AppealDecision;
+    id: number;
+  };
+} | {
+  ban: {
+    address: string;
+    expires_at?: Timestamp | null;
+    reason: string;
+  };
+} | {
+  unban: {
+    address: string;
+  };
+} | {
+  slash: {
+    address: string;
+    limit?: number | null;
+    start_after?: string | null;
   };
 } | {
   update_staking_info: {
     address: string;
   };
+} | {
+  update_staking_info_batch: {
+    addresses: string[];
+  };
+} | {
+  update_staking_info_next: {
+    limit: number;
+  };
+} | {
+  register: {};
+} | {
+  unvouch: {
+    address: string;
+    amount?: Uint128 | null;
+  };
+} | {
+  claim_unvouched: {};
+} | {
+  receive: Cw20ReceiveMsg;
+} | {
+  attest: {
+    address: string;
+    expires_at?: Timestamp | null;
+    kind: string;
+    uri?: string | null;
+  };
+} | {
+  revoke_attestation: {
+    address: string;
+    attester?: string | null;
+    kind: string;
+  };
+} | {
+  stake_change_hook: StakeChangedHookMsg;
 } | {
   register_pending_review: {
     order_id: number;
+    order_value?: Uint128 | null;
     peer: string;
+    peer_role?: TradeRole | null;
     reviewer: string;
   };
 } | {
   review: {
     address: string;
+    comment?: ReviewComment | null;
+    review: ReviewResult;
+  };
+} | {
+  commit_review: {
+    address: string;
+    commitment: string;
+  };
+} | {
+  reveal_review: {
+    address: string;
+    comment?: ReviewComment | null;
     review: ReviewResult;
+    salt: string;
+  };
+} | {
+  appeal_review: {
+    id: number;
+    reason?: ReviewComment | null;
+  };
+} | {
+  reply_to_review: {
+    id: number;
+    reply: ReviewComment;
+  };
+} | {
+  settle_review: {
+    address: string;
+  };
+};
+export type AppealDecision = "uphold" | "strike";
+export type Timestamp = Uint64;
+export type Uint64 = string;
+export type Binary = string;
+export type StakeChangedHookMsg = {
+  stake: {
+    addr: Addr;
+    amount: Uint128;
+  };
+} | {
+  unstake: {
+    addr: Addr;
+    amount: Uint128;
+  };
+};
+export type Addr = string;
+export type TradeRole = "buyer" | "seller" | "unknown";
+export type ReviewComment = {
+  text: string;
+} | {
+  anchored: {
+    hash: string;
+    uri: string;
   };
 };
 export type ReviewResult = "thumbs_up" | "thumbs_down";
+export interface Cw20ReceiveMsg {
+  amount: Uint128;
+  msg: Binary;
+  sender: string;
+}
 export type QueryMsg = {
   config: {};
+} | {
+  commerce_code_ids: {};
+} | {
+  commerce_contracts: {};
+} | {
+  attesters: {};
+} | {
+  ban: {
+    address: string;
+  };
+} | {
+  bans: {
+    limit?: number | null;
+    start_after?: string | null;
+  };
 } | {
   trust_info: {
     address: string;
   };
+} | {
+  tier: {
+    address: string;
+  };
+} | {
+  store_scoped_trust: {
+    address: string;
+    commerce_contract: string;
+  };
+} | {
+  trust_info_at_height: {
+    address: string;
+    height: number;
+  };
+} | {
+  score_breakdown: {
+    address: string;
+  };
+} | {
+  simulate_score: {
+    data: TrustData;
+  };
 } | {
   stake_amount: {
     address: string;
   };
 } | {
   accounts: {};
+} | {
+  top_accounts: {
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  rank: {
+    address: string;
+  };
 } | {
   pending_review: {
     peer: string;
@@ -72,54 +355,336 @@ export type QueryMsg = {
   pending_reviews_by_reviewer: {
     reviewer: string;
   };
+} | {
+  review: {
+    id: number;
+  };
+} | {
+  reviews_by_peer: {
+    limit?: number | null;
+    peer: string;
+    start_after?: number | null;
+  };
+} | {
+  reviews_by_reviewer: {
+    limit?: number | null;
+    reviewer: string;
+    start_after?: number | null;
+  };
+} | {
+  vouches_given: {
+    voucher: string;
+  };
+} | {
+  vouches_received: {
+    vouchee: string;
+  };
+} | {
+  unbondings: {
+    voucher: string;
+  };
+} | {
+  appeal: {
+    id: number;
+  };
+} | {
+  appeals_by_peer: {
+    peer: string;
+  };
+} | {
+  appeals: {
+    limit?: number | null;
+    start_after?: number | null;
+    status?: AppealStatus | null;
+  };
+} | {
+  pair_review_counter: {
+    peer: string;
+    reviewer: string;
+  };
+} | {
+  pair_review_counters: {
+    limit?: number | null;
+    peer: string;
+    start_after?: string | null;
+  };
 };
-export type Addr = string;
+export type StakeSource = {
+  cw20_stake: {
+    contract: Addr;
+  };
+} | {
+  native: {
+    denom: string;
+  };
+};
+export type AppealStatus = "pending" | "upheld" | "struck";
+export interface TrustData {
+  attestations: Attestation[];
+  ban?: Ban | null;
+  buyer_rating: RoleRating;
+  created_at: Timestamp;
+  decayed_rating: number;
+  decayed_rating_weight: number;
+  last_synced?: Timestamp | null;
+  rating: number;
+  rating_updated_at: Timestamp;
+  rating_weight: number;
+  seller_rating: RoleRating;
+  stake_days: number;
+  stake_start?: Timestamp | null;
+  stakes: SourceStake[];
+  vouched_amount: Decimal;
+}
+export interface Attestation {
+  attester: Addr;
+  expires_at?: Timestamp | null;
+  issued_at: Timestamp;
+  kind: string;
+  score_bonus: number;
+  uri?: string | null;
+}
+export interface Ban {
+  banned_at: Timestamp;
+  expires_at?: Timestamp | null;
+  reason: string;
+}
+export interface RoleRating {
+  decayed_rating: number;
+  decayed_rating_weight: number;
+  rating: number;
+  rating_weight: number;
+}
+export interface SourceStake {
+  amount: Uint128;
+  source: StakeSource;
+}
+export interface MigrateMsg {
+  vouching?: VouchingParamsMsg | null;
+}
 export interface AccountsResponse {
   accounts: Addr[];
 }
+export interface AppealResponse {
+  appeal?: Appeal | null;
+}
+export interface Appeal {
+  created_at: Timestamp;
+  decided_at?: Timestamp | null;
+  peer: Addr;
+  rating_delta: number;
+  reason?: ReviewComment | null;
+  review_id: number;
+  status: AppealStatus;
+}
+export interface AppealsResponse {
+  appeals: Appeal[];
+}
+export interface AttestersResponse {
+  attesters: AttesterInfo[];
+}
+export interface AttesterInfo {
+  address: Addr;
+  attester: Attester;
+}
+export interface Attester {
+  name: string;
+  score_bonus: number;
+}
+export interface BanResponse {
+  active: boolean;
+  ban?: Ban | null;
+}
+export interface BansResponse {
+  bans: BanInfo[];
+}
+export interface BanInfo {
+  address: Addr;
+  ban: Ban;
+}
+export interface CommerceCodeIdsResponse {
+  code_ids: number[];
+}
+export interface CommerceContractsResponse {
+  contracts: Addr[];
+}
 export interface ConfigResponse {
   config: Config;
 }
 export interface Config {
   admin: Addr;
-  commerce_code_id: number;
-  maintainer: Addr;
+  appeal_window: number;
+  maintainer?: Addr | null;
   max_rating: number;
   max_staked_days: number;
-  max_staked_tokens: Uint128;
+  min_sync_interval: number;
+  pair_review_limit: PairReviewLimit;
+  rating_half_life: number;
   review_interval: number;
-  staking_contract: Addr;
-  trust_score_params: TrustScoreParams;
+  review_weight: ReviewWeightParams;
+  reviewer_eligibility: ReviewerEligibility;
+  score_model: ScoreModel;
+  staking_sources: StakingSource[];
+  tiers: Tier[];
+  vouching: VouchingParams;
+}
+export interface StakingSource {
+  denom_multiplier: number;
+  max_staked_tokens: Uint128;
+  source: StakeSource;
+  weight: Decimal;
+}
+export interface VouchingParams {
+  denom_multiplier: number;
+  max_bonus: number;
+  reference_score: number;
+  score_per_token: Decimal;
+  slash_share: Decimal;
+  token: Addr;
+  unbonding_period: number;
+  voucher_eligibility: VoucherEligibility;
+}
+export interface PairReviewCounterResponse {
+  counter?: PairReviewCounter | null;
+}
+export interface PairReviewCounter {
+  reviews: number;
+  total_reviews: number;
+  weight: number;
+  window_start: Timestamp;
+}
+export interface PairReviewCountersResponse {
+  counters: ReviewerCounter[];
+}
+export interface ReviewerCounter {
+  counter: PairReviewCounter;
+  reviewer: Addr;
 }
-export type Timestamp = Uint64;
-export type Uint64 = string;
 export interface PendingReviewResponse {
   pending_review?: PendingReview | null;
 }
 export interface PendingReview {
   commerce_contract: Addr;
+  commitment?: string | null;
   expires_at: Timestamp;
   order_id: number;
+  order_value?: Uint128 | null;
   peer: Addr;
+  peer_role: TradeRole;
   reviewer: Addr;
 }
 export interface PendingReviewsResponse {
   pending_reviews: PendingReview[];
 }
+export interface RankResponse {
+  capped: boolean;
+  rank?: number | null;
+}
+export interface ReviewResponse {
+  review?: Review | null;
+}
+export interface Review {
+  comment?: ReviewComment | null;
+  commerce_contract: Addr;
+  created_at: Timestamp;
+  id: number;
+  order_id: number;
+  peer: Addr;
+  peer_role: TradeRole;
+  rating_delta: number;
+  reply?: ReviewComment | null;
+  result: ReviewResult;
+  reviewer: Addr;
+}
+export interface ReviewsResponse {
+  reviews: Review[];
+}
+export interface ScoreBreakdownResponse {
+  breakdown?: ScoreBreakdown | null;
+}
+export interface ScoreBreakdown {
+  attestations: ScoreComponent;
+  base: ScoreComponent;
+  max_score: Decimal;
+  rating: ScoreComponent;
+  rating_floor: ScoreComponent;
+  score: Decimal;
+  stake: ScoreComponent;
+  vouches: ScoreComponent;
+}
+export interface ScoreComponent {
+  negative: boolean;
+  value: Decimal;
+}
+export interface SimulateScoreResponse {
+  breakdown: ScoreBreakdown;
+}
 export interface StakeAmountResponse {
+  sources: SourceStakeAmount[];
+  stake_amount: Decimal;
+}
+export interface SourceStakeAmount {
+  source: StakeSource;
   stake_amount?: Uint128 | null;
+  weighted_amount: Decimal;
+}
+export interface StoreScopedTrustResponse {
+  score?: Decimal | null;
+  store_rating?: StoreRating | null;
+}
+export interface StoreRating {
+  decayed_rating: number;
+  decayed_rating_weight: number;
+  rating: number;
+  rating_updated_at: Timestamp;
+  rating_weight: number;
+  reviews: number;
+}
+export interface TierResponse {
+  score: Decimal;
+  tier?: Tier | null;
+}
+export interface TopAccountsResponse {
+  accounts: AccountScore[];
+}
+export interface AccountScore {
+  address: Addr;
+  score: Decimal;
 }
-export type Decimal = string;
 export interface TrustInfoResponse {
+  role_scores?: RoleScores | null;
+  stale: boolean;
+  tier?: Tier | null;
   trust_info?: TrustInfo | null;
 }
+export interface RoleScores {
+  buyer: Decimal;
+  seller: Decimal;
+}
 export interface TrustInfo {
   data: TrustData;
   score: Decimal;
 }
-export interface TrustData {
-  prev_stake_amount: Uint128;
-  rating: number;
-  stake_amount: Uint128;
-  stake_days: number;
+export interface TrustInfoAtHeightResponse {
+  height: number;
+  trust_info?: TrustInfo | null;
+}
+export interface UnbondingsResponse {
+  unbondings: Unbonding[];
+}
+export interface Unbonding {
+  amount: Uint128;
+  release_at: Timestamp;
+  vouchee: Addr;
+  voucher: Addr;
+}
+export interface VouchesResponse {
+  vouches: Vouch[];
+}
+export interface Vouch {
+  amount: Uint128;
+  created_at: Timestamp;
+  vouchee: Addr;
+  voucher: Addr;
+  weighted_amount: Decimal;
 }
\ No newline at end of file